@@ -9,7 +9,7 @@
  * File Created: 2025-10-23 15:16:03
  *
  * Modified By: S.EE Development Team <dev@s.ee>
- * Last Modified: 2025-12-04 19:24:58
+ * Last Modified: 2026-10-19 11:41:27
  */
 
 use see_sdk::client::Client;
@@ -50,7 +50,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create a URL with all available options
     println!("\n🔗 Creating shortened URL with custom options...");
     let request = UrlShortenerRequestBuilder::new("https://www.example.com/product/123")?
-        .with_custom_alias("summer-sale")
+        .with_custom_alias("summer-sale")?
         .with_expiration(1830297599) // 2027-12-31T23:59:59Z
         .build();
 
@@ -9,13 +9,13 @@
  * File Created: 2026-01-19 23:39:29
  *
  * Modified By: S.EE Development Team <dev@s.ee>
- * Last Modified: 2026-01-19 23:49:36
+ * Last Modified: 2026-10-19 10:02:44
  */
 
 use see_sdk::client::Client;
-use see_sdk::config::{Config, DEFAULT_DOMAIN};
+use see_sdk::config::Config;
 use see_sdk::text::TextService;
-use see_sdk::text::models::{CreateTextRequest, DeleteTextRequest, UpdateTextRequest};
+use see_sdk::text::models::CreateTextRequest;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 1. Initialize client
@@ -36,12 +36,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         text_type: None,
     };
 
-    let create_resp = client.create_text(create_req)?;
-    println!("Created! Short URL: {}", create_resp.data.short_url);
-    println!("Slug: {}", create_resp.data.slug);
-
-    let slug = create_resp.data.slug;
-    let domain = DEFAULT_DOMAIN.to_string(); // In real usage, you might want to parse it from short_url if needed
+    let mut text = client.create_text_handle(create_req)?;
+    println!("Created! Short URL: {}", text.short_url());
+    println!("Slug: {}", text.slug());
 
     // 3. Get available domains
     println!("\nFetching text domains...");
@@ -50,21 +47,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // 4. Update the text snippet
     println!("\nUpdating text snippet...");
-    let update_req = UpdateTextRequest {
-        content: "This content has been updated.".to_string(),
-        domain: domain.clone(),
-        slug: slug.clone(),
-        title: "Updated Rust Snippet".to_string(),
-    };
-
-    client.update_text(update_req)?;
+    text.update("This content has been updated.", "Updated Rust Snippet")?;
     println!("Text snippet updated successfully.");
 
     // 5. Delete the text snippet
     println!("\nDeleting text snippet...");
-    let delete_req = DeleteTextRequest { domain, slug };
-
-    client.delete_text(delete_req)?;
+    text.delete()?;
     println!("Text snippet deleted successfully.");
 
     Ok(())
@@ -0,0 +1,257 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: batch.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-19 14:48:02
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-19 15:37:44
+ */
+
+//! Batch operations running on a bounded pool of worker threads.
+//!
+//! Every request still goes through [`Client`], so the configured rate limit
+//! and retry policy apply to each item.
+
+use crate::client::Client;
+use crate::error::Result;
+use crate::file::FileService;
+use crate::file::models::FileUploadResponse;
+use crate::text::TextService;
+use crate::text::models::{CreateTextRequest, CreateTextResponse};
+use crate::url::ShortenService;
+use crate::url::models::{DeleteRequest, DeleteResponse, ShortenRequest, ShortenResponse};
+use std::path::Path;
+use std::sync::Mutex;
+use std::thread;
+
+/// Default number of requests running at the same time
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Options for batch operations
+#[derive(Debug, Clone)]
+pub struct BatchOptions {
+    /// Maximum number of requests running at the same time
+    pub concurrency: usize,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+}
+
+impl BatchOptions {
+    /// Set the maximum number of requests running at the same time
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+}
+
+/// Counts of a finished batch
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BatchSummary {
+    pub total: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+}
+
+/// Per-item results of a batch, in input order
+#[derive(Debug)]
+pub struct BatchResult<T> {
+    pub results: Vec<Result<T>>,
+    pub summary: BatchSummary,
+}
+
+impl<T> BatchResult<T> {
+    fn from_results(results: Vec<Result<T>>) -> Self {
+        let succeeded = results.iter().filter(|r| r.is_ok()).count();
+
+        Self {
+            summary: BatchSummary {
+                total: results.len(),
+                succeeded,
+                failed: results.len() - succeeded,
+            },
+            results,
+        }
+    }
+
+    /// Whether every item succeeded
+    pub fn is_success(&self) -> bool {
+        self.summary.failed == 0
+    }
+}
+
+/// Service for running many requests at once
+pub trait BatchService {
+    /// Shorten many URLs
+    fn shorten_many(
+        &self,
+        requests: Vec<ShortenRequest>,
+        options: &BatchOptions,
+    ) -> BatchResult<ShortenResponse>;
+
+    /// Create many text sharing entries
+    fn create_texts(
+        &self,
+        requests: Vec<CreateTextRequest>,
+        options: &BatchOptions,
+    ) -> BatchResult<CreateTextResponse>;
+
+    /// Upload many files
+    fn upload_files<P: AsRef<Path> + Sync>(
+        &self,
+        file_paths: &[P],
+        options: &BatchOptions,
+    ) -> BatchResult<FileUploadResponse>;
+
+    /// Delete many short URLs
+    fn delete_many(
+        &self,
+        requests: Vec<DeleteRequest>,
+        options: &BatchOptions,
+    ) -> BatchResult<DeleteResponse>;
+}
+
+impl BatchService for Client {
+    fn shorten_many(
+        &self,
+        requests: Vec<ShortenRequest>,
+        options: &BatchOptions,
+    ) -> BatchResult<ShortenResponse> {
+        run_batch(requests, options, |request| self.shorten(request))
+    }
+
+    fn create_texts(
+        &self,
+        requests: Vec<CreateTextRequest>,
+        options: &BatchOptions,
+    ) -> BatchResult<CreateTextResponse> {
+        run_batch(requests, options, |request| self.create_text(request))
+    }
+
+    fn upload_files<P: AsRef<Path> + Sync>(
+        &self,
+        file_paths: &[P],
+        options: &BatchOptions,
+    ) -> BatchResult<FileUploadResponse> {
+        run_batch(file_paths.iter().collect(), options, |path| {
+            self.upload_file(path)
+        })
+    }
+
+    fn delete_many(
+        &self,
+        requests: Vec<DeleteRequest>,
+        options: &BatchOptions,
+    ) -> BatchResult<DeleteResponse> {
+        run_batch(requests, options, |request| self.delete(request))
+    }
+}
+
+/// Run `f` over all items with at most `options.concurrency` worker threads,
+/// keeping the results in input order
+pub(crate) fn run_batch<I, T, F>(items: Vec<I>, options: &BatchOptions, f: F) -> BatchResult<T>
+where
+    I: Send,
+    T: Send,
+    F: Fn(I) -> Result<T> + Sync,
+{
+    let total = items.len();
+    let workers = options.concurrency.clamp(1, total.max(1));
+    let queue = Mutex::new(items.into_iter().enumerate());
+
+    let mut indexed: Vec<(usize, Result<T>)> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let next = queue.lock().unwrap_or_else(|e| e.into_inner()).next();
+                        match next {
+                            Some((index, item)) => done.push((index, f(item))),
+                            None => return done,
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("batch worker panicked"))
+            .collect()
+    });
+
+    indexed.sort_by_key(|(index, _)| *index);
+    BatchResult::from_results(indexed.into_iter().map(|(_, result)| result).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[test]
+    fn test_run_batch_keeps_input_order() {
+        let items: Vec<u64> = (0..20).collect();
+        let options = BatchOptions::default().with_concurrency(5);
+
+        let result = run_batch(items, &options, |n| {
+            // Finish later items first to shuffle completion order
+            thread::sleep(Duration::from_millis(20 - n));
+            if n == 7 {
+                Err(Error::NotFound)
+            } else {
+                Ok(n * 2)
+            }
+        });
+
+        assert_eq!(result.summary.total, 20);
+        assert_eq!(result.summary.succeeded, 19);
+        assert_eq!(result.summary.failed, 1);
+        assert!(!result.is_success());
+
+        for (n, item) in result.results.iter().enumerate() {
+            match item {
+                Ok(value) => assert_eq!(*value, n as u64 * 2),
+                Err(_) => assert_eq!(n, 7),
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_batch_bounds_concurrency() {
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let options = BatchOptions::default().with_concurrency(3);
+
+        let result = run_batch((0..12).collect(), &options, |n: i32| {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(5));
+            running.fetch_sub(1, Ordering::SeqCst);
+            Ok(n)
+        });
+
+        assert!(result.is_success());
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+    }
+
+    #[test]
+    fn test_run_batch_empty() {
+        let result = run_batch(Vec::<u8>::new(), &BatchOptions::default(), Ok);
+        assert_eq!(result.summary, BatchSummary::default());
+        assert!(result.is_success());
+    }
+}
@@ -0,0 +1,577 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: cli.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-19 23:41:08
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 09:04:37
+ */
+
+//! Command-line arguments.
+
+use crate::completions::{domain_candidates, tag_candidates};
+use crate::output::OutputFormat;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use clap_complete::engine::ArgValueCandidates;
+use see_sdk::batch::DEFAULT_CONCURRENCY;
+use see_sdk::export::ExportFormat;
+use see_sdk::import::ImportFormat;
+use see_sdk::ledger::ResourceKind;
+use see_sdk::types::{DomainName, FileKey, Slug, TagId};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Default maximum size of a text read by `see text`, in bytes
+pub const DEFAULT_MAX_TEXT_SIZE: u64 = 1024 * 1024;
+
+/// Shorten links, share text and upload files with S.EE
+#[derive(Debug, Parser)]
+#[command(name = "see", version, about)]
+pub struct Cli {
+    #[command(flatten)]
+    pub global: GlobalArgs,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// Options shared by all subcommands
+#[derive(Debug, Args)]
+pub struct GlobalArgs {
+    /// Config file with named profiles
+    #[arg(long, global = true, env = "SEE_CONFIG", value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// Profile of the config file to use
+    #[arg(long, global = true, env = "SEE_PROFILE")]
+    pub profile: Option<String>,
+
+    /// API key, overriding the config file and SEE_API_KEY; `-` reads it
+    /// from the first line of stdin
+    ///
+    /// Prefer SEE_API_KEY or the config file: a key given on the command
+    /// line shows up in the process list and shell history.
+    #[arg(long, global = true, value_name = "KEY")]
+    pub api_key: Option<String>,
+
+    /// API base URL
+    #[arg(long, global = true, value_name = "URL")]
+    pub base_url: Option<String>,
+
+    /// Output format
+    #[arg(short, long, global = true, value_enum, default_value_t)]
+    pub output: OutputFormat,
+
+    /// Validate and print requests without sending them
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
+    /// Log requests and retries to stderr
+    #[arg(short, long, global = true)]
+    pub verbose: bool,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Shorten a URL
+    Shorten(ShortenArgs),
+
+    /// Manage short links
+    #[command(subcommand)]
+    Link(LinkCommand),
+
+    /// Share text from stdin, files or globs
+    Text(Box<TextArgs>),
+
+    /// Upload and delete files
+    #[command(subcommand)]
+    File(FileCommand),
+
+    /// Upload files as they appear in a directory
+    Watch(WatchArgs),
+
+    /// Shorten every link listed in a CSV or JSON file
+    Import(ImportArgs),
+
+    /// Export the links, texts and files recorded in the ledger
+    Export(ExportArgs),
+
+    /// List tags
+    Tags,
+
+    /// List available domains
+    Domains(DomainsArgs),
+
+    /// Check the configuration, network and API key
+    Doctor,
+
+    /// Print the script that enables completions for a shell
+    ///
+    /// Domains and tags are completed from those listed by the last
+    /// `see domains` and `see tags`.
+    Completions {
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+
+    /// Write man pages for `see` and its subcommands
+    Man {
+        /// Directory to write the pages to
+        #[arg(default_value = ".")]
+        dir: PathBuf,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct ShortenArgs {
+    /// The URL to shorten
+    pub url: String,
+
+    /// Custom slug
+    #[arg(long, value_parser = |slug: &str| Slug::custom(slug))]
+    pub slug: Option<Slug>,
+
+    /// Domain to shorten on, the configured default domain otherwise
+    #[arg(long, add = ArgValueCandidates::new(domain_candidates))]
+    pub domain: Option<DomainName>,
+
+    /// Title of the link
+    #[arg(long)]
+    pub title: Option<String>,
+
+    /// Expiration time (Unix epoch seconds)
+    #[arg(long, value_name = "TIMESTAMP")]
+    pub expire_at: Option<i64>,
+
+    /// URL to redirect to after expiration
+    #[arg(long, value_name = "URL")]
+    pub expiration_redirect_url: Option<String>,
+
+    /// Password required to follow the link
+    #[arg(long)]
+    pub password: Option<String>,
+
+    /// Tag to attach, may be repeated
+    #[arg(long = "tag", value_name = "TAG_ID", add = ArgValueCandidates::new(tag_candidates))]
+    pub tags: Vec<TagId>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum LinkCommand {
+    /// Change the target of a short link
+    Update {
+        /// Slug of the link
+        slug: Slug,
+
+        /// New target URL
+        target_url: String,
+
+        #[command(flatten)]
+        domain: DomainArg,
+
+        /// New title
+        #[arg(long)]
+        title: Option<String>,
+    },
+
+    /// Delete a short link
+    Delete {
+        /// Slug of the link
+        slug: Slug,
+
+        #[command(flatten)]
+        domain: DomainArg,
+    },
+
+    /// Show visit statistics of a short link
+    Stats {
+        /// Slug of the link
+        slug: Slug,
+
+        #[command(flatten)]
+        domain: DomainArg,
+
+        /// Statistics period, such as `day` or `month`
+        #[arg(long)]
+        period: Option<String>,
+    },
+}
+
+/// `see text` shares text by default, other actions are subcommands
+#[derive(Debug, Args)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct TextArgs {
+    #[command(subcommand)]
+    pub command: Option<TextCommand>,
+
+    #[command(flatten)]
+    pub create: TextCreateArgs,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum TextCommand {
+    /// Share a text
+    Create(TextCreateArgs),
+
+    /// Replace the content of a shared text
+    Update {
+        /// Slug of the text
+        slug: Slug,
+
+        /// New content
+        content: String,
+
+        /// New title
+        #[arg(long)]
+        title: String,
+
+        #[command(flatten)]
+        domain: DomainArg,
+    },
+
+    /// Delete a shared text
+    Delete {
+        /// Slug of the text
+        slug: Slug,
+
+        #[command(flatten)]
+        domain: DomainArg,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct TextCreateArgs {
+    /// Files or glob patterns to share, one text each; stdin when none or `-`
+    #[arg(value_name = "FILE")]
+    pub inputs: Vec<String>,
+
+    /// Share this text instead of reading stdin or files
+    #[arg(short, long, conflicts_with = "inputs")]
+    pub content: Option<String>,
+
+    /// Title of the text, the file name by default
+    #[arg(long)]
+    pub title: Option<String>,
+
+    /// Domain to use, the service picks one by default
+    #[arg(long, add = ArgValueCandidates::new(domain_candidates))]
+    pub domain: Option<DomainName>,
+
+    /// Custom slug
+    #[arg(long, value_parser = |slug: &str| Slug::custom(slug))]
+    pub slug: Option<Slug>,
+
+    /// Expiration time (Unix epoch seconds)
+    #[arg(long, value_name = "TIMESTAMP")]
+    pub expire_at: Option<i64>,
+
+    /// Expire after a duration, such as `30m`, `24h` or `7days`
+    #[arg(long, value_name = "DURATION", value_parser = humantime::parse_duration, conflicts_with = "expire_at")]
+    pub expires: Option<Duration>,
+
+    /// Password required to read the text
+    #[arg(long)]
+    pub password: Option<String>,
+
+    /// Tag to attach, may be repeated
+    #[arg(long = "tag", value_name = "TAG_ID", add = ArgValueCandidates::new(tag_candidates))]
+    pub tags: Vec<TagId>,
+
+    /// Text type, such as `plain_text`, `markdown` or `source_code`;
+    /// detected from the file extension by default
+    #[arg(long = "type", value_name = "TYPE")]
+    pub text_type: Option<String>,
+
+    /// Refuse texts larger than this many bytes
+    #[arg(long, value_name = "BYTES", default_value_t = DEFAULT_MAX_TEXT_SIZE)]
+    pub max_size: u64,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum FileCommand {
+    /// Upload a file
+    Upload {
+        /// The file to upload
+        path: PathBuf,
+    },
+
+    /// Delete an uploaded file
+    Delete {
+        /// Key (hash) of the file
+        key: FileKey,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct WatchArgs {
+    /// Directory to watch
+    pub dir: PathBuf,
+
+    /// How long a file must stay unchanged before it is uploaded
+    #[arg(long, value_name = "DURATION", value_parser = humantime::parse_duration, default_value = "2s")]
+    pub settle: Duration,
+
+    /// Also watch subdirectories
+    #[arg(short, long)]
+    pub recursive: bool,
+
+    /// Shell command to run after each upload, with the URL on stdin and in
+    /// `$SEE_URL`, such as `wl-copy` or `pbcopy`
+    #[arg(long, value_name = "COMMAND")]
+    pub notify: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct ImportArgs {
+    /// CSV or JSON file with one link per row
+    pub file: PathBuf,
+
+    /// Format of the file, guessed from its extension by default
+    #[arg(long, value_enum)]
+    pub format: Option<ImportFileFormat>,
+
+    /// Where to write the results, `<FILE>.results.csv` by default
+    #[arg(long, value_name = "PATH")]
+    pub report: Option<PathBuf>,
+
+    /// Domain for rows without one, the configured default domain otherwise
+    #[arg(long, add = ArgValueCandidates::new(domain_candidates))]
+    pub domain: Option<DomainName>,
+
+    /// Maximum number of requests running at the same time
+    #[arg(long, default_value_t = DEFAULT_CONCURRENCY)]
+    pub concurrency: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ImportFileFormat {
+    Csv,
+    Json,
+}
+
+impl From<ImportFileFormat> for ImportFormat {
+    fn from(format: ImportFileFormat) -> Self {
+        match format {
+            ImportFileFormat::Csv => ImportFormat::Csv,
+            ImportFileFormat::Json => ImportFormat::Json,
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct ExportArgs {
+    /// File to write, stdout when omitted or `-`
+    pub path: Option<PathBuf>,
+
+    /// Format to write, guessed from the file extension, CSV otherwise
+    #[arg(long, value_enum)]
+    pub format: Option<ExportFileFormat>,
+
+    /// Ledger to read instead of the configured one
+    #[arg(long, value_name = "PATH")]
+    pub ledger: Option<PathBuf>,
+
+    /// Only export this kind of resource
+    #[arg(long, value_enum)]
+    pub kind: Option<DomainKind>,
+
+    /// Only export resources carrying this tag
+    #[arg(long, value_name = "TAG_ID", add = ArgValueCandidates::new(tag_candidates))]
+    pub tag: Option<TagId>,
+
+    /// Only export resources on this domain
+    #[arg(long, add = ArgValueCandidates::new(domain_candidates))]
+    pub domain: Option<DomainName>,
+
+    /// Skip fetching the visit count of every link
+    #[arg(long)]
+    pub no_stats: bool,
+
+    /// Period to count visits over, such as `7d`
+    #[arg(long, conflicts_with = "no_stats")]
+    pub period: Option<String>,
+
+    /// Maximum number of requests running at the same time
+    #[arg(long, default_value_t = DEFAULT_CONCURRENCY)]
+    pub concurrency: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFileFormat {
+    Csv,
+    Jsonl,
+}
+
+impl From<ExportFileFormat> for ExportFormat {
+    fn from(format: ExportFileFormat) -> Self {
+        match format {
+            ExportFileFormat::Csv => ExportFormat::Csv,
+            ExportFileFormat::Jsonl => ExportFormat::JsonLines,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl Shell {
+    /// Name of the shell as known to the completion engine
+    pub fn name(self) -> &'static str {
+        match self {
+            Shell::Bash => "bash",
+            Shell::Zsh => "zsh",
+            Shell::Fish => "fish",
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct DomainsArgs {
+    /// Which service to list domains for
+    #[arg(long = "for", value_enum, default_value_t = DomainKind::Link)]
+    pub kind: DomainKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DomainKind {
+    Link,
+    Text,
+    File,
+}
+
+impl From<DomainKind> for ResourceKind {
+    fn from(kind: DomainKind) -> Self {
+        match kind {
+            DomainKind::Link => ResourceKind::Link,
+            DomainKind::Text => ResourceKind::Text,
+            DomainKind::File => ResourceKind::File,
+        }
+    }
+}
+
+/// Domain of an existing short link or text
+#[derive(Debug, Args)]
+pub struct DomainArg {
+    /// Domain of the short URL, the configured default domain otherwise
+    #[arg(long, add = ArgValueCandidates::new(domain_candidates))]
+    pub domain: Option<DomainName>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_parse_shorten() {
+        let cli = Cli::try_parse_from([
+            "see",
+            "shorten",
+            "https://example.com/",
+            "--slug",
+            "launch",
+            "--tag",
+            "3",
+            "--tag",
+            "4",
+            "--dry-run",
+        ])
+        .unwrap();
+
+        assert!(cli.global.dry_run);
+        let Command::Shorten(args) = cli.command else {
+            panic!("expected shorten");
+        };
+        assert_eq!(args.slug.unwrap(), "launch");
+        assert_eq!(args.tags, [TagId::new(3), TagId::new(4)]);
+
+        assert!(
+            Cli::try_parse_from(["see", "shorten", "https://example.com/", "--slug", "a b"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_text() {
+        let cli = Cli::try_parse_from([
+            "see",
+            "text",
+            "--title",
+            "build-log",
+            "--type",
+            "plain_text",
+            "--expires",
+            "24h",
+        ])
+        .unwrap();
+
+        let Command::Text(args) = cli.command else {
+            panic!("expected text");
+        };
+        assert!(args.command.is_none());
+        assert!(args.create.inputs.is_empty());
+        assert_eq!(args.create.text_type.as_deref(), Some("plain_text"));
+        assert_eq!(args.create.expires, Some(Duration::from_secs(24 * 3600)));
+
+        let cli = Cli::try_parse_from(["see", "text", "delete", "abc"]).unwrap();
+        let Command::Text(args) = cli.command else {
+            panic!("expected text");
+        };
+        assert!(matches!(args.command, Some(TextCommand::Delete { .. })));
+    }
+
+    #[test]
+    fn test_parse_import() {
+        let cli = Cli::try_parse_from(["see", "import", "links.txt", "--format", "json"]).unwrap();
+
+        let Command::Import(args) = cli.command else {
+            panic!("expected import");
+        };
+        assert_eq!(args.file, PathBuf::from("links.txt"));
+        assert_eq!(args.format, Some(ImportFileFormat::Json));
+        assert_eq!(args.concurrency, DEFAULT_CONCURRENCY);
+        assert!(args.report.is_none());
+    }
+
+    #[test]
+    fn test_parse_export() {
+        let cli = Cli::try_parse_from(["see", "export", "links.jsonl", "--kind", "link"]).unwrap();
+
+        let Command::Export(args) = cli.command else {
+            panic!("expected export");
+        };
+        assert_eq!(args.kind, Some(DomainKind::Link));
+        assert!(!args.no_stats);
+
+        assert!(Cli::try_parse_from(["see", "export", "--no-stats", "--period", "7d"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_watch() {
+        let cli = Cli::try_parse_from([
+            "see", "watch", "shots", "--settle", "500ms", "--notify", "wl-copy",
+        ])
+        .unwrap();
+
+        let Command::Watch(args) = cli.command else {
+            panic!("expected watch");
+        };
+        assert_eq!(args.settle, Duration::from_millis(500));
+        assert_eq!(args.notify.as_deref(), Some("wl-copy"));
+        assert!(!args.recursive);
+    }
+}
@@ -0,0 +1,441 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: commands.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-19 23:58:47
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 08:52:18
+ */
+
+//! Subcommands, each mapped onto a service call.
+
+use crate::cli::{
+    Command, DomainArg, DomainKind, DomainsArgs, ExportArgs, FileCommand, ImportArgs, LinkCommand,
+    ShortenArgs, TextArgs, TextCommand, TextCreateArgs, WatchArgs,
+};
+use crate::completions::CompletionCache;
+use crate::input::{self, Paste};
+use crate::output::{ImportSummary, OutputFormat, emit};
+use indicatif::{ProgressBar, ProgressStyle};
+use see_sdk::batch::BatchOptions;
+use see_sdk::client::Client;
+use see_sdk::domain::DomainService;
+use see_sdk::error::{Error, Result};
+use see_sdk::export::{self, ExportFormat};
+use see_sdk::file::FileService;
+use see_sdk::import::{self, ImportFormat};
+use see_sdk::ledger::{Ledger, LedgerQuery};
+use see_sdk::tag::TagService;
+use see_sdk::text::TextService;
+use see_sdk::text::models::{CreateTextRequest, DeleteTextRequest, UpdateTextRequest};
+use see_sdk::types::{DomainName, Secret};
+use see_sdk::url::ShortenService;
+use see_sdk::url::models::{
+    DeleteRequest, GetLinkVisitStatRequest, ShortenRequest, UpdateShortURLRequest,
+};
+use see_sdk::watch::{self, NotifyHook, WatchEvent, WatchOptions};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::ops::ControlFlow;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Run a subcommand and print its result
+pub fn run(client: &Client, command: Command, format: OutputFormat) -> Result<()> {
+    match command {
+        Command::Shorten(args) => shorten(client, args, format),
+        Command::Link(command) => link(client, command, format),
+        Command::Text(args) => text(client, *args, format),
+        Command::File(command) => file(client, command, format),
+        Command::Watch(args) => watch_dir(client, args, format),
+        Command::Import(args) => import_links(client, args, format),
+        Command::Export(args) => export_resources(client, args),
+        Command::Tags => tags(client, format),
+        Command::Domains(args) => domains(client, args, format),
+        Command::Doctor | Command::Completions { .. } | Command::Man { .. } => {
+            unreachable!("handled before the client is created")
+        }
+    }
+}
+
+/// The domain given on the command line, else the configured default
+fn domain_or_default(client: &Client, domain: Option<DomainName>) -> DomainName {
+    domain
+        .or_else(|| client.config().default_domain.clone())
+        .unwrap_or_default()
+}
+
+fn shorten(client: &Client, args: ShortenArgs, format: OutputFormat) -> Result<()> {
+    let request = ShortenRequest {
+        target_url: args.url,
+        domain: args.domain,
+        title: args.title,
+        custom_slug: args.slug,
+        expiration_redirect_url: args.expiration_redirect_url,
+        expire_at: args.expire_at,
+        password: args.password.map(Secret::new),
+        tag_ids: (!args.tags.is_empty()).then_some(args.tags),
+    };
+
+    emit(&client.shorten(request)?, format)
+}
+
+fn link(client: &Client, command: LinkCommand, format: OutputFormat) -> Result<()> {
+    match command {
+        LinkCommand::Update {
+            slug,
+            target_url,
+            domain: DomainArg { domain },
+            title,
+        } => {
+            let response = client.update_short_url(UpdateShortURLRequest {
+                domain: domain_or_default(client, domain),
+                slug,
+                target_url,
+                title,
+            })?;
+            emit(&response, format)?;
+        }
+        LinkCommand::Delete {
+            slug,
+            domain: DomainArg { domain },
+        } => {
+            let response = client.delete(DeleteRequest {
+                domain: domain_or_default(client, domain),
+                slug,
+            })?;
+            emit(&response, format)?;
+        }
+        LinkCommand::Stats {
+            slug,
+            domain: DomainArg { domain },
+            period,
+        } => {
+            let response = client.get_link_visit_stat(GetLinkVisitStatRequest {
+                domain: domain_or_default(client, domain),
+                slug,
+                period,
+            })?;
+            emit(&response, format)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn text(client: &Client, args: TextArgs, format: OutputFormat) -> Result<()> {
+    let Some(command) = args.command else {
+        return create_text(client, args.create, format);
+    };
+
+    match command {
+        TextCommand::Create(args) => create_text(client, args, format),
+        TextCommand::Update {
+            slug,
+            content,
+            title,
+            domain: DomainArg { domain },
+        } => {
+            let response = client.update_text(UpdateTextRequest {
+                content,
+                domain: domain_or_default(client, domain),
+                slug,
+                title,
+            })?;
+            emit(&response, format)?;
+            Ok(())
+        }
+        TextCommand::Delete {
+            slug,
+            domain: DomainArg { domain },
+        } => {
+            let response = client.delete_text(DeleteTextRequest {
+                domain: domain_or_default(client, domain),
+                slug,
+            })?;
+            emit(&response, format)?;
+            Ok(())
+        }
+    }
+}
+
+/// Title of a text shared from stdin without `--title`
+const STDIN_TITLE: &str = "stdin";
+
+/// Share the text given with `--content`, or one text per input
+fn create_text(client: &Client, args: TextCreateArgs, format: OutputFormat) -> Result<()> {
+    let pastes = match args.content {
+        Some(content) if content.len() as u64 > args.max_size => {
+            return Err(Error::GenericError(format!(
+                "content is larger than {} bytes, see --max-size",
+                args.max_size
+            )));
+        }
+        Some(content) => vec![Paste {
+            content,
+            name: None,
+            text_type: None,
+        }],
+        None => input::read_pastes(&args.inputs, args.max_size)?,
+    };
+
+    if pastes.len() > 1 && args.slug.is_some() {
+        return Err(Error::GenericError(
+            "--slug can only be used with a single text".to_string(),
+        ));
+    }
+
+    let expire_at = match args.expires {
+        Some(expires) => Some(now() + expires.as_secs() as i64),
+        None => args.expire_at,
+    };
+    let tag_ids = (!args.tags.is_empty()).then_some(args.tags);
+
+    for paste in pastes {
+        let response = client.create_text(CreateTextRequest {
+            content: paste.content,
+            title: args
+                .title
+                .clone()
+                .or(paste.name)
+                .unwrap_or_else(|| STDIN_TITLE.to_string()),
+            domain: args.domain.clone(),
+            custom_slug: args.slug.clone(),
+            expire_at,
+            password: args.password.clone().map(Secret::new),
+            tag_ids: tag_ids.clone(),
+            text_type: args
+                .text_type
+                .clone()
+                .or(paste.text_type.map(str::to_string)),
+        })?;
+
+        emit(&response, format)?;
+    }
+
+    Ok(())
+}
+
+/// Current time in Unix epoch seconds
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+fn file(client: &Client, command: FileCommand, format: OutputFormat) -> Result<()> {
+    match command {
+        FileCommand::Upload { path } => {
+            emit(&client.upload_file(&path)?, format)?;
+        }
+        FileCommand::Delete { key } => {
+            let response = client.delete_file(&key)?;
+            if !response.success {
+                return Err(Error::ServerError {
+                    status: 0,
+                    message: response.message,
+                });
+            }
+            emit(&response, format)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Upload files as they settle in a directory, until interrupted
+fn watch_dir(client: &Client, args: WatchArgs, format: OutputFormat) -> Result<()> {
+    let mut options = WatchOptions::default()
+        .with_settle(args.settle)
+        .with_recursive(args.recursive);
+    if let Some(command) = args.notify {
+        options = options.with_hook(shell_command(command));
+    }
+
+    watch::watch(client, &args.dir, &options, |event| {
+        match event {
+            WatchEvent::Uploaded(upload) => {
+                if let Err(e) = emit(&upload, format) {
+                    eprintln!("see: {}", e);
+                }
+            }
+            WatchEvent::Failed { path, error } => eprintln!("see: {}: {}", path.display(), error),
+        }
+        ControlFlow::Continue(())
+    })
+}
+
+/// A hook running `command` through the platform shell
+fn shell_command(command: String) -> NotifyHook {
+    if cfg!(windows) {
+        NotifyHook::new("cmd").arg("/C").arg(command)
+    } else {
+        NotifyHook::new("sh").arg("-c").arg(command)
+    }
+}
+
+/// Progress bar template used while importing
+const IMPORT_PROGRESS_TEMPLATE: &str = "{bar:40} {pos}/{len} links ({eta} left)";
+
+/// Validate the whole file, shorten every row and write the results CSV
+fn import_links(client: &Client, args: ImportArgs, format: OutputFormat) -> Result<()> {
+    let file_format = args
+        .format
+        .map(ImportFormat::from)
+        .or_else(|| ImportFormat::from_path(&args.file))
+        .ok_or_else(|| {
+            Error::GenericError(format!(
+                "cannot tell the format of {}, see --format",
+                args.file.display()
+            ))
+        })?;
+
+    let default_domain = domain_or_default(client, args.domain);
+    let plan = import::plan(File::open(&args.file)?, file_format, &default_domain)?;
+
+    if !plan.is_valid() {
+        for error in &plan.errors {
+            eprintln!("{}", error);
+        }
+        return Err(Error::GenericError(format!(
+            "{} invalid rows in {}, nothing was imported",
+            plan.errors.len(),
+            args.file.display()
+        )));
+    }
+
+    let progress = ProgressBar::new(plan.len() as u64);
+    if let Ok(style) = ProgressStyle::with_template(IMPORT_PROGRESS_TEMPLATE) {
+        progress.set_style(style);
+    }
+
+    let options = BatchOptions::default().with_concurrency(args.concurrency);
+    let report = import::run(client, plan, &options, || progress.inc(1))?;
+    progress.finish_and_clear();
+
+    let report_path = args.report.unwrap_or_else(|| {
+        let mut path = args.file.clone().into_os_string();
+        path.push(".results.csv");
+        PathBuf::from(path)
+    });
+    report.write_csv(BufWriter::new(File::create(&report_path)?))?;
+
+    emit(
+        &ImportSummary {
+            total: report.summary.total,
+            succeeded: report.summary.succeeded,
+            failed: report.summary.failed,
+            report: report_path.clone(),
+        },
+        format,
+    )?;
+
+    if !report.is_success() {
+        return Err(Error::GenericError(format!(
+            "{} of {} links failed, see {}",
+            report.summary.failed,
+            report.summary.total,
+            report_path.display()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Write the live resources of the ledger, with link visit counts
+fn export_resources(client: &Client, args: ExportArgs) -> Result<()> {
+    let opened;
+    let ledger = match (&args.ledger, client.ledger()) {
+        (Some(path), _) => {
+            opened = Ledger::open(path)?;
+            &opened
+        }
+        (None, Some(ledger)) => ledger,
+        (None, None) => {
+            return Err(Error::ConfigError(
+                "no ledger configured, set ledger_path in the profile or pass --ledger".to_string(),
+            ));
+        }
+    };
+
+    let mut query = LedgerQuery::new();
+    if let Some(kind) = args.kind {
+        query = query.with_kind(kind.into());
+    }
+    if let Some(tag) = args.tag {
+        query = query.with_tag(tag);
+    }
+    if let Some(domain) = args.domain {
+        query = query.with_domain(domain);
+    }
+
+    let mut records = export::collect(ledger, &query)?;
+    if !args.no_stats {
+        let options = BatchOptions::default().with_concurrency(args.concurrency);
+        records = export::fetch_stats(client, records, args.period.as_deref(), &options);
+    }
+
+    let path = args.path.filter(|path| path != Path::new("-"));
+    let file_format = args
+        .format
+        .map(ExportFormat::from)
+        .or_else(|| path.as_ref().and_then(ExportFormat::from_path))
+        .unwrap_or(ExportFormat::Csv);
+
+    let writer: Box<dyn Write> = match &path {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(io::stdout().lock()),
+    };
+    export::write(&records, file_format, writer)
+}
+
+/// Diagnose the client, or why it couldn't be created, failing if any check failed
+pub fn doctor(client: Result<Client>, format: OutputFormat) -> Result<()> {
+    let diagnosis = match &client {
+        Ok(client) => see_sdk::doctor::diagnose(client),
+        Err(e) => see_sdk::doctor::config_failure(e),
+    };
+    emit(&diagnosis, format)?;
+
+    match diagnosis.failures().next() {
+        Some(failure) => Err(Error::GenericError(format!(
+            "{} check failed: {}",
+            failure.name, failure.detail
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// List tags, remembering them for completion
+fn tags(client: &Client, format: OutputFormat) -> Result<()> {
+    let response = TagService::list(client)?;
+    CompletionCache::update(|cache| cache.tags = response.data.tags.clone());
+    emit(&response, format)
+}
+
+/// List domains, remembering link and text domains for completion
+fn domains(client: &Client, args: DomainsArgs, format: OutputFormat) -> Result<()> {
+    match args.kind {
+        DomainKind::Link => {
+            let response = DomainService::list(client)?;
+            remember_domains(&response.data.domains);
+            emit(&response, format)
+        }
+        DomainKind::Text => {
+            let response = client.get_text_domains()?;
+            remember_domains(&response.data.domains);
+            emit(&response, format)
+        }
+        DomainKind::File => emit(&client.get_file_domains()?, format),
+    }
+}
+
+fn remember_domains(domains: &[DomainName]) {
+    CompletionCache::update(|cache| cache.domains.extend(domains.iter().cloned()));
+}
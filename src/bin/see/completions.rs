@@ -0,0 +1,180 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: completions.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-20 03:38:51
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 03:38:51
+ */
+
+//! Shell completions and man pages.
+//!
+//! Completions run through `see` itself: the shell calls it with
+//! [`COMPLETE_VAR`] set and the words typed so far. Domains and tags are
+//! completed from a local cache refreshed by `see domains` and `see tags`,
+//! so pressing tab never waits on the network.
+
+use crate::cli::{Cli, Shell};
+use clap::CommandFactory;
+use clap_complete::engine::CompletionCandidate;
+use clap_complete::env::Shells;
+use see_sdk::error::{Error, Result};
+use see_sdk::tag::Tag;
+use see_sdk::types::DomainName;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Environment variable that switches `see` into completion mode
+pub const COMPLETE_VAR: &str = "COMPLETE";
+
+/// Name of the binary being completed
+const BIN_NAME: &str = "see";
+
+/// Domains and tags seen by earlier commands
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CompletionCache {
+    #[serde(default)]
+    pub domains: BTreeSet<DomainName>,
+
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+}
+
+impl CompletionCache {
+    /// Read the cache, empty when missing or unreadable
+    pub fn load() -> Self {
+        cache_path()
+            .and_then(|path| Self::load_from(&path).ok())
+            .unwrap_or_default()
+    }
+
+    fn load_from(path: &Path) -> Result<Self> {
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Change the cache and write it back
+    ///
+    /// The cache only speeds up completion, so failing to write it is
+    /// logged and otherwise ignored.
+    pub fn update(change: impl FnOnce(&mut Self)) {
+        let Some(path) = cache_path() else {
+            return;
+        };
+
+        let mut cache = Self::load_from(&path).unwrap_or_default();
+        change(&mut cache);
+        if let Err(e) = cache.save_to(&path) {
+            log::warn!("failed to write {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// `$XDG_CACHE_HOME/see/completions.json`, or `~/.cache/see/completions.json`
+fn cache_path() -> Option<PathBuf> {
+    let cache_home = env::var_os("XDG_CACHE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+
+    Some(cache_home.join("see").join("completions.json"))
+}
+
+/// Cached domains, for `--domain`
+pub fn domain_candidates() -> Vec<CompletionCandidate> {
+    CompletionCache::load()
+        .domains
+        .into_iter()
+        .map(|domain| CompletionCandidate::new(domain.to_string()))
+        .collect()
+}
+
+/// Cached tag IDs described by their names, for `--tag`
+pub fn tag_candidates() -> Vec<CompletionCandidate> {
+    CompletionCache::load()
+        .tags
+        .into_iter()
+        .map(|tag| CompletionCandidate::new(tag.id.to_string()).help(Some(tag.name.into())))
+        .collect()
+}
+
+/// Print the script that registers `see` completions with the shell
+pub fn write_registration(shell: Shell) -> Result<()> {
+    let shells = Shells::builtins();
+    let completer = shells
+        .completer(shell.name())
+        .ok_or_else(|| Error::GenericError(format!("no completions for {}", shell.name())))?;
+
+    let mut stdout = io::stdout().lock();
+    completer.write_registration(COMPLETE_VAR, BIN_NAME, BIN_NAME, BIN_NAME, &mut stdout)?;
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Write a man page for `see` and each of its subcommands into `dir`
+pub fn write_man_pages(dir: &Path) -> Result<()> {
+    fs::create_dir_all(dir)?;
+    clap_mangen::generate_to(Cli::command(), dir)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use see_sdk::types::TagId;
+
+    #[test]
+    fn test_cache_round_trip() -> Result<()> {
+        let mut path = env::temp_dir();
+        path.push(format!("see-completions-{}", std::process::id()));
+        let file = path.join("completions.json");
+
+        let cache = CompletionCache {
+            domains: ["s.ee", "example.link"]
+                .into_iter()
+                .map(|domain| DomainName::new(domain).unwrap())
+                .collect(),
+            tags: vec![Tag {
+                id: TagId::new(7),
+                name: "campaign".to_string(),
+            }],
+        };
+        cache.save_to(&file)?;
+
+        let loaded = CompletionCache::load_from(&file)?;
+        assert_eq!(loaded.domains, cache.domains);
+        assert_eq!(loaded.tags[0].name, "campaign");
+
+        fs::remove_dir_all(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_man_pages_for_subcommands() -> Result<()> {
+        let mut dir = env::temp_dir();
+        dir.push(format!("see-man-{}", std::process::id()));
+
+        write_man_pages(&dir)?;
+        assert!(dir.join("see.1").exists());
+        assert!(dir.join("see-link-stats.1").exists());
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: input.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-20 01:40:52
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 09:04:37
+ */
+
+//! Reading texts to share from stdin, files and globs.
+
+use see_sdk::error::{Error, Result};
+use std::fs::File;
+use std::io::{self, BufRead, IsTerminal, Read};
+use std::path::{Path, PathBuf};
+
+/// Argument naming stdin
+const STDIN: &str = "-";
+
+/// A text read from one input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paste {
+    pub content: String,
+    /// File name of the input, `None` for stdin
+    pub name: Option<String>,
+    /// Text type detected from the file extension
+    pub text_type: Option<&'static str>,
+}
+
+/// Read every input, expanding glob patterns; stdin when there are none
+///
+/// All inputs are read and checked against `max_size` before returning, so
+/// nothing is shared when one of them is invalid.
+pub fn read_pastes(inputs: &[String], max_size: u64) -> Result<Vec<Paste>> {
+    if inputs.is_empty() {
+        return Ok(vec![read_stdin(max_size)?]);
+    }
+
+    let mut pastes = Vec::new();
+    for input in inputs {
+        if input == STDIN {
+            pastes.push(read_stdin(max_size)?);
+            continue;
+        }
+
+        for path in expand(input)? {
+            pastes.push(read_file(&path, max_size)?);
+        }
+    }
+
+    Ok(pastes)
+}
+
+/// The paths an input names, matching it as a glob if it is one
+fn expand(input: &str) -> Result<Vec<PathBuf>> {
+    if !input.contains(['*', '?', '[']) {
+        return Ok(vec![PathBuf::from(input)]);
+    }
+
+    let pattern = glob::glob(input)
+        .map_err(|e| Error::GenericError(format!("invalid pattern {:?}: {}", input, e)))?;
+
+    let mut paths = Vec::new();
+    for entry in pattern {
+        let path = entry.map_err(|e| Error::IoError(e.into()))?;
+        if path.is_file() {
+            paths.push(path);
+        }
+    }
+
+    if paths.is_empty() {
+        return Err(Error::GenericError(format!("no files match {:?}", input)));
+    }
+
+    paths.sort();
+    Ok(paths)
+}
+
+fn read_stdin(max_size: u64) -> Result<Paste> {
+    let stdin = io::stdin();
+    if stdin.is_terminal() {
+        return Err(Error::GenericError(
+            "no input: pass files or pipe text into see".to_string(),
+        ));
+    }
+
+    Ok(Paste {
+        content: read_limited(stdin.lock(), "stdin", max_size)?,
+        name: None,
+        text_type: None,
+    })
+}
+
+fn read_file(path: &Path, max_size: u64) -> Result<Paste> {
+    let file = File::open(path)?;
+    let label = path.display().to_string();
+
+    Ok(Paste {
+        content: read_limited(file, &label, max_size)?,
+        name: path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned()),
+        text_type: detect_text_type(path),
+    })
+}
+
+/// Read at most `max_size` bytes of UTF-8 text, failing on more
+fn read_limited(reader: impl Read, label: &str, max_size: u64) -> Result<String> {
+    let mut content = Vec::new();
+    reader.take(max_size + 1).read_to_end(&mut content)?;
+
+    if content.len() as u64 > max_size {
+        return Err(Error::GenericError(format!(
+            "{} is larger than {} bytes, see --max-size",
+            label, max_size
+        )));
+    }
+    if content.is_empty() {
+        return Err(Error::GenericError(format!("{} is empty", label)));
+    }
+
+    String::from_utf8(content)
+        .map_err(|_| Error::GenericError(format!("{} is not UTF-8 text", label)))
+}
+
+/// The `--api-key` value, reading the key from stdin when it is `-`
+pub fn api_key(arg: &str) -> Result<String> {
+    if arg == STDIN {
+        read_key(io::stdin().lock())
+    } else {
+        Ok(arg.to_string())
+    }
+}
+
+/// The first line of `reader`, trimmed, failing when it is blank
+fn read_key(mut reader: impl BufRead) -> Result<String> {
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+
+    match line.trim() {
+        "" => Err(Error::ConfigError("no API key on stdin".to_string())),
+        key => Ok(key.to_string()),
+    }
+}
+
+/// The text type matching a file extension
+pub fn detect_text_type(path: &Path) -> Option<&'static str> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+
+    Some(match extension.as_str() {
+        "md" | "markdown" => "markdown",
+        "txt" | "text" | "log" => "plain_text",
+        "rs" | "go" | "py" | "js" | "ts" | "jsx" | "tsx" | "java" | "kt" | "c" | "h" | "cc"
+        | "cpp" | "hpp" | "cs" | "rb" | "php" | "swift" | "sh" | "bash" | "zsh" | "sql"
+        | "html" | "css" | "scss" | "json" | "yaml" | "yml" | "toml" | "xml" | "ini" => {
+            "source_code"
+        }
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn test_detect_text_type() {
+        assert_eq!(detect_text_type(Path::new("README.md")), Some("markdown"));
+        assert_eq!(detect_text_type(Path::new("build.LOG")), Some("plain_text"));
+        assert_eq!(detect_text_type(Path::new("main.rs")), Some("source_code"));
+        assert_eq!(detect_text_type(Path::new("image.png")), None);
+        assert_eq!(detect_text_type(Path::new("Makefile")), None);
+    }
+
+    #[test]
+    fn test_read_key() {
+        assert_eq!(read_key(&b"secret\nrest"[..]).unwrap(), "secret");
+        assert_eq!(read_key(&b"  secret\r\n"[..]).unwrap(), "secret");
+        assert!(read_key(&b"\n"[..]).is_err());
+        assert!(read_key(&b""[..]).is_err());
+        assert_eq!(api_key("literal").unwrap(), "literal");
+    }
+
+    #[test]
+    fn test_read_limited() {
+        assert_eq!(read_limited(&b"hello"[..], "input", 5).unwrap(), "hello");
+        assert!(read_limited(&b"hello!"[..], "input", 5).is_err());
+        assert!(read_limited(&b""[..], "input", 5).is_err());
+        assert!(read_limited(&[0xff, 0xfe][..], "input", 5).is_err());
+    }
+
+    #[test]
+    fn test_read_pastes_expands_globs() {
+        let mut dir = env::temp_dir();
+        dir.push(format!("see-cli-input-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a.md"), "# A").unwrap();
+        fs::write(dir.join("b.log"), "line").unwrap();
+
+        let pattern = format!("{}/*", dir.display());
+        let pastes = read_pastes(&[pattern], 1024).unwrap();
+        assert_eq!(pastes.len(), 2);
+        assert_eq!(pastes[0].name.as_deref(), Some("a.md"));
+        assert_eq!(pastes[0].text_type, Some("markdown"));
+        assert_eq!(pastes[1].content, "line");
+
+        let missing = format!("{}/*.rs", dir.display());
+        assert!(read_pastes(&[missing], 1024).is_err());
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,212 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: main.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-19 23:41:08
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 09:04:37
+ */
+
+//! The `see` command-line tool.
+//!
+//! Configuration comes from, in increasing precedence: the config file
+//! profile, the `SEE_*` environment variables and the command-line flags.
+
+mod cli;
+mod commands;
+mod completions;
+mod input;
+mod output;
+
+use clap::{CommandFactory, Parser};
+use clap_complete::CompleteEnv;
+use cli::{Cli, Command, GlobalArgs};
+use see_sdk::client::Client;
+use see_sdk::config::{Config, ConfigFile, DEFAULT_PROFILE};
+use see_sdk::error::{Error, Result};
+use std::env;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    CompleteEnv::with_factory(Cli::command)
+        .var(completions::COMPLETE_VAR)
+        .complete();
+
+    let cli = Cli::parse();
+    StderrLogger::init(&cli.global);
+
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("see: {}", e);
+            ExitCode::from(exit_code(&e))
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<()> {
+    match cli.command {
+        Command::Completions { shell } => completions::write_registration(shell),
+        Command::Man { dir } => completions::write_man_pages(&dir),
+        // Configuration errors are diagnosed rather than reported
+        Command::Doctor => commands::doctor(
+            load_config(&cli.global).and_then(Client::new),
+            cli.global.output,
+        ),
+        command => {
+            let client = Client::new(load_config(&cli.global)?)?;
+            commands::run(&client, command, cli.global.output)
+        }
+    }
+}
+
+/// Exit status for an error
+///
+/// | code | meaning |
+/// |------|---------|
+/// | 1 | other error |
+/// | 2 | invalid command line (reported by clap) |
+/// | 3 | invalid input, such as a malformed URL or slug |
+/// | 4 | not found |
+/// | 5 | conflict, such as a slug that is taken |
+/// | 6 | rate limited |
+/// | 7 | authentication failed |
+/// | 8 | other server error or unexpected response |
+/// | 9 | network error |
+/// | 10 | configuration error |
+/// | 11 | local I/O error |
+fn exit_code(error: &Error) -> u8 {
+    match error {
+        Error::InvalidUrl(_)
+        | Error::InvalidSlug(_)
+        | Error::InvalidDomain(_)
+        | Error::InvalidFileKey(_)
+        | Error::UrlParseError(_) => 3,
+        Error::NotFound => 4,
+        e if e.is_conflict() => 5,
+        Error::RateLimited => 6,
+        Error::ServerError {
+            status: 401 | 403, ..
+        }
+        | Error::CredentialError(_) => 7,
+        Error::ServerError { .. } | Error::JsonError(_) => 8,
+        Error::HttpError(_) => 9,
+        Error::ConfigError(_) => 10,
+        Error::IoError(_) => 11,
+        Error::GenericError(_) => 1,
+    }
+}
+
+/// Build the configuration from the config file, environment and flags
+fn load_config(args: &GlobalArgs) -> Result<Config> {
+    let path = args.config.clone().or_else(default_config_path);
+
+    let config = match path {
+        Some(path) if args.config.is_some() || path.exists() => {
+            let file = ConfigFile::load(&path)?;
+            match &args.profile {
+                Some(profile) => file.profile(profile)?,
+                None if file.profiles.contains_key(DEFAULT_PROFILE) => {
+                    file.profile(DEFAULT_PROFILE)?
+                }
+                None => Config::default(),
+            }
+        }
+        _ => match &args.profile {
+            Some(profile) => {
+                return Err(Error::ConfigError(format!(
+                    "profile {:?} requested but no config file found",
+                    profile
+                )));
+            }
+            None => Config::default(),
+        },
+    };
+
+    let mut config = config.with_env()?.with_dry_run(args.dry_run);
+    if let Some(api_key) = &args.api_key {
+        config = config.with_api_key(input::api_key(api_key)?);
+    }
+    if let Some(base_url) = &args.base_url {
+        config.base_url = base_url.clone();
+    }
+
+    Ok(config)
+}
+
+/// `$XDG_CONFIG_HOME/see/config.toml`, or `~/.config/see/config.toml`
+fn default_config_path() -> Option<PathBuf> {
+    let config_home = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(config_home.join("see").join("config.toml"))
+}
+
+/// Writes SDK log records to stderr
+struct StderrLogger;
+
+impl StderrLogger {
+    /// Log warnings, plus requests in dry-run or verbose mode
+    fn init(args: &GlobalArgs) {
+        let level = if args.verbose {
+            log::LevelFilter::Debug
+        } else if args.dry_run {
+            log::LevelFilter::Info
+        } else {
+            log::LevelFilter::Warn
+        };
+
+        if log::set_logger(&StderrLogger).is_ok() {
+            log::set_max_level(level);
+        }
+    }
+}
+
+impl log::Log for StderrLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        let target = metadata.target();
+        target.starts_with("see_sdk") || target == "see" || target.starts_with("see::")
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!(
+                "{}: {}",
+                record.level().as_str().to_lowercase(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_codes() {
+        let server_error = |status, message: &str| Error::ServerError {
+            status,
+            message: message.to_string(),
+        };
+
+        assert_eq!(exit_code(&Error::InvalidSlug("a b".to_string())), 3);
+        assert_eq!(exit_code(&Error::NotFound), 4);
+        assert_eq!(exit_code(&server_error(400, "slug already exists")), 5);
+        assert_eq!(exit_code(&Error::RateLimited), 6);
+        assert_eq!(exit_code(&server_error(401, "unauthorized")), 7);
+        assert_eq!(exit_code(&server_error(500, "internal error")), 8);
+        assert_eq!(exit_code(&Error::ConfigError(String::new())), 10);
+        assert_eq!(exit_code(&Error::GenericError(String::new())), 1);
+    }
+}
@@ -0,0 +1,389 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: output.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-20 00:41:19
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 05:06:33
+ */
+
+//! Rendering of command results in the `--output` formats.
+
+use clap::ValueEnum;
+use see_sdk::doctor::Diagnosis;
+use see_sdk::domain::DomainListResponse;
+use see_sdk::error::{Error, Result};
+use see_sdk::file::models::{FileDeleteResponse, FileDomainsResponse, FileUploadResponse};
+use see_sdk::tag::TagListResponse;
+use see_sdk::text::models::{
+    CreateTextResponse, DeleteTextResponse, TextDomainsResponse, UpdateTextResponse,
+};
+use see_sdk::types::DomainName;
+use see_sdk::url::models::{DeleteResponse, GetLinkVisitStatResponse, ShortenResponse};
+use see_sdk::watch::Upload;
+use serde::Serialize;
+use std::fmt;
+use std::path::PathBuf;
+
+/// How command results are printed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// The response model as JSON
+    Json,
+    /// The response model as YAML
+    Yaml,
+    /// Aligned columns with a header
+    Table,
+    /// The essential values, one per line
+    #[default]
+    Plain,
+    /// Only the short or file URLs, for piping
+    UrlOnly,
+}
+
+/// A command result printable in every output format
+pub trait Output: Serialize {
+    /// Header and rows for table output
+    fn table(&self) -> Table;
+
+    /// Lines for plain output
+    fn plain(&self) -> Vec<String>;
+
+    /// URLs for url-only output; results without one print as plain
+    fn urls(&self) -> Option<Vec<String>> {
+        None
+    }
+}
+
+/// Print a result in the given format
+pub fn emit(output: &impl Output, format: OutputFormat) -> Result<()> {
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(output)?),
+        OutputFormat::Yaml => print!(
+            "{}",
+            serde_yaml::to_string(output).map_err(|e| Error::GenericError(e.to_string()))?
+        ),
+        OutputFormat::Table => print!("{}", output.table()),
+        OutputFormat::Plain => print_lines(output.plain()),
+        OutputFormat::UrlOnly => print_lines(output.urls().unwrap_or_else(|| output.plain())),
+    }
+
+    Ok(())
+}
+
+fn print_lines(lines: Vec<String>) {
+    for line in lines {
+        println!("{}", line);
+    }
+}
+
+/// Rows under a header, printed with aligned columns
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    header: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(header: Vec<&'static str>) -> Self {
+        Self {
+            header,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Add a row; missing cells are left empty
+    pub fn row(mut self, row: Vec<String>) -> Self {
+        self.rows.push(row);
+        self
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header: Vec<String> = self.header.iter().map(|h| h.to_uppercase()).collect();
+
+        let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        for row in std::iter::once(&header).chain(&self.rows) {
+            let last = widths.len() - 1;
+            for (i, width) in widths.iter().enumerate() {
+                let cell = row.get(i).map(String::as_str).unwrap_or_default();
+                if i == last {
+                    writeln!(f, "{}", cell)?;
+                } else {
+                    write!(f, "{:<width$}  ", cell, width = width)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Counts printed by `see import`
+#[derive(Debug, Serialize)]
+pub struct ImportSummary {
+    pub total: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+
+    /// The CSV with the short URL or error of every row
+    pub report: PathBuf,
+}
+
+impl Output for ImportSummary {
+    fn table(&self) -> Table {
+        Table::new(vec!["total", "succeeded", "failed", "report"]).row(vec![
+            self.total.to_string(),
+            self.succeeded.to_string(),
+            self.failed.to_string(),
+            self.report.display().to_string(),
+        ])
+    }
+
+    fn plain(&self) -> Vec<String> {
+        vec![format!(
+            "imported {} of {} links, results in {}",
+            self.succeeded,
+            self.total,
+            self.report.display()
+        )]
+    }
+}
+
+impl Output for Diagnosis {
+    fn table(&self) -> Table {
+        self.checks.iter().fold(
+            Table::new(vec!["check", "status", "detail", "hint"]),
+            |table, check| {
+                table.row(vec![
+                    check.name.to_string(),
+                    check.status.to_string(),
+                    check.detail.clone(),
+                    check.hint.clone().unwrap_or_default(),
+                ])
+            },
+        )
+    }
+
+    fn plain(&self) -> Vec<String> {
+        self.to_string().lines().map(str::to_string).collect()
+    }
+}
+
+impl Output for Upload {
+    fn table(&self) -> Table {
+        Table::new(vec!["file", "url", "delete_url"]).row(vec![
+            self.path.display().to_string(),
+            self.url.clone(),
+            self.delete_url.clone(),
+        ])
+    }
+
+    fn plain(&self) -> Vec<String> {
+        vec![format!("{} {}", self.path.display(), self.url)]
+    }
+
+    fn urls(&self) -> Option<Vec<String>> {
+        Some(vec![self.url.clone()])
+    }
+}
+
+impl Output for ShortenResponse {
+    fn table(&self) -> Table {
+        Table::new(vec!["slug", "short_url"]).row(vec![
+            self.data.slug.to_string(),
+            self.data.short_url.clone(),
+        ])
+    }
+
+    fn plain(&self) -> Vec<String> {
+        vec![self.data.short_url.clone()]
+    }
+
+    fn urls(&self) -> Option<Vec<String>> {
+        Some(self.plain())
+    }
+}
+
+impl Output for CreateTextResponse {
+    fn table(&self) -> Table {
+        Table::new(vec!["slug", "short_url"]).row(vec![
+            self.data.slug.to_string(),
+            self.data.short_url.clone(),
+        ])
+    }
+
+    fn plain(&self) -> Vec<String> {
+        vec![self.data.short_url.clone()]
+    }
+
+    fn urls(&self) -> Option<Vec<String>> {
+        Some(self.plain())
+    }
+}
+
+impl Output for FileUploadResponse {
+    fn table(&self) -> Table {
+        Table::new(vec!["key", "filename", "size", "url", "delete_url"]).row(vec![
+            self.data.hash.to_string(),
+            self.data.filename.clone(),
+            self.data.size.to_string(),
+            self.data.url.clone(),
+            self.data.delete.clone(),
+        ])
+    }
+
+    fn plain(&self) -> Vec<String> {
+        vec![
+            self.data.url.clone(),
+            format!("key: {}", self.data.hash),
+            format!("delete: {}", self.data.delete),
+        ]
+    }
+
+    fn urls(&self) -> Option<Vec<String>> {
+        Some(vec![self.data.url.clone()])
+    }
+}
+
+impl Output for GetLinkVisitStatResponse {
+    fn table(&self) -> Table {
+        Table::new(vec!["visits"]).row(vec![self.data.visit_count.to_string()])
+    }
+
+    fn plain(&self) -> Vec<String> {
+        vec![self.data.visit_count.to_string()]
+    }
+}
+
+/// Responses that only carry a status message
+macro_rules! message_output {
+    ($($response:ty),*) => {
+        $(
+            impl Output for $response {
+                fn table(&self) -> Table {
+                    Table::new(vec!["code", "message"])
+                        .row(vec![self.code.to_string(), self.message.clone()])
+                }
+
+                fn plain(&self) -> Vec<String> {
+                    vec![self.message.clone()]
+                }
+            }
+        )*
+    };
+}
+
+message_output!(
+    DeleteResponse,
+    UpdateTextResponse,
+    DeleteTextResponse,
+    FileDeleteResponse
+);
+
+impl Output for TagListResponse {
+    fn table(&self) -> Table {
+        self.data
+            .tags
+            .iter()
+            .fold(Table::new(vec!["id", "name"]), |table, tag| {
+                table.row(vec![tag.id.to_string(), tag.name.clone()])
+            })
+    }
+
+    fn plain(&self) -> Vec<String> {
+        self.data
+            .tags
+            .iter()
+            .map(|tag| format!("{}\t{}", tag.id, tag.name))
+            .collect()
+    }
+}
+
+fn domain_table(domains: &[DomainName]) -> Table {
+    domains
+        .iter()
+        .fold(Table::new(vec!["domain"]), |table, domain| {
+            table.row(vec![domain.to_string()])
+        })
+}
+
+fn domain_lines(domains: &[DomainName]) -> Vec<String> {
+    domains.iter().map(DomainName::to_string).collect()
+}
+
+/// Domain list responses of the different services
+macro_rules! domains_output {
+    ($($response:ty),*) => {
+        $(
+            impl Output for $response {
+                fn table(&self) -> Table {
+                    domain_table(&self.data.domains)
+                }
+
+                fn plain(&self) -> Vec<String> {
+                    domain_lines(&self.data.domains)
+                }
+            }
+        )*
+    };
+}
+
+domains_output!(DomainListResponse, TextDomainsResponse, FileDomainsResponse);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use see_sdk::types::Slug;
+    use see_sdk::url::models::ShortenData;
+
+    fn shorten_response() -> ShortenResponse {
+        ShortenResponse {
+            code: 200,
+            message: "success".to_string(),
+            data: ShortenData {
+                custom_slug: None,
+                short_url: "https://s.ee/abc".to_string(),
+                slug: Slug::new("abc").unwrap(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_table_alignment() {
+        let table = Table::new(vec!["id", "name"])
+            .row(vec!["1".to_string(), "marketing".to_string()])
+            .row(vec!["100".to_string(), "ops".to_string()]);
+
+        assert_eq!(table.to_string(), "ID   NAME\n1    marketing\n100  ops\n");
+    }
+
+    #[test]
+    fn test_output_variants() {
+        let response = shorten_response();
+
+        assert_eq!(response.plain(), ["https://s.ee/abc"]);
+        assert_eq!(response.urls().unwrap(), ["https://s.ee/abc"]);
+        assert!(response.table().to_string().starts_with("SLUG"));
+
+        let deleted = DeleteResponse {
+            code: 200,
+            message: "deleted".to_string(),
+            data: None,
+        };
+        assert!(deleted.urls().is_none());
+        assert_eq!(deleted.plain(), ["deleted"]);
+    }
+}
@@ -9,13 +9,18 @@
  * File Created: 2025-10-23 11:29:29
  *
  * Modified By: S.EE Development Team <dev@s.ee>
- * Last Modified: 2026-01-20 11:16:52
+ * Last Modified: 2026-10-20 06:40:27
  */
 use crate::config::Config;
 use crate::error::{Error, ErrorResponse, Result};
+use crate::ledger::{Ledger, LedgerEntry};
+use crate::telemetry::RequestTelemetry;
 use reqwest::StatusCode;
-use reqwest::blocking::{Client as HttpClient, Response};
-use std::sync::Arc;
+use reqwest::blocking::{Client as HttpClient, RequestBuilder, Response};
+use reqwest::header::{HeaderMap, HeaderValue};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
 use url::Url;
 
 /// HTTP client for content sharing operations
@@ -23,22 +28,121 @@ use url::Url;
 pub struct Client {
     http_client: HttpClient,
     config: Arc<Config>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    ledger: Option<Arc<Ledger>>,
+}
+
+/// Spaces requests evenly to stay under a requests-per-second limit
+#[derive(Debug)]
+struct RateLimiter {
+    interval: Duration,
+    next_slot: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    fn new(requests_per_second: u32) -> Self {
+        Self {
+            interval: Duration::from_secs(1) / requests_per_second.max(1),
+            next_slot: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Block until the next request may be sent
+    fn acquire(&self) {
+        let wait = {
+            let mut next_slot = self.next_slot.lock().unwrap_or_else(|e| e.into_inner());
+            let now = Instant::now();
+            let slot = (*next_slot).max(now);
+            *next_slot = slot + self.interval;
+            slot - now
+        };
+
+        if !wait.is_zero() {
+            thread::sleep(wait);
+        }
+    }
 }
 
 impl Client {
     /// Create a new content sharing client with the given configuration
+    ///
+    /// The HTTP client is `config.http_client` if set, and is otherwise
+    /// built from `config.http`.
     pub fn new(config: Config) -> Result<Self> {
-        let http_client = HttpClient::builder()
-            .timeout(config.timeout)
-            .user_agent(&config.user_agent)
-            .build()?;
+        let http_client = match &config.http_client {
+            Some(http_client) => http_client.clone(),
+            None => config.http.build_client()?,
+        };
+
+        Self::with_http_client(config, http_client)
+    }
+
+    /// Create a client that sends its requests through an existing HTTP
+    /// client, sharing its connection pool
+    ///
+    /// The timeout and user agent of `config` are applied to each request.
+    pub fn with_http_client(config: Config, http_client: HttpClient) -> Result<Self> {
+        let ledger = match &config.ledger_path {
+            Some(path) => Some(Arc::new(Ledger::open(path)?)),
+            None => None,
+        };
 
         Ok(Self {
             http_client,
+            ledger,
+            rate_limiter: config.rate_limit.map(|rps| Arc::new(RateLimiter::new(rps))),
             config: Arc::new(config),
         })
     }
 
+    /// The configuration this client was created with
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// The ledger this client records to, if one is configured
+    pub fn ledger(&self) -> Option<&Ledger> {
+        self.ledger.as_deref()
+    }
+
+    /// Append an entry to the ledger, if one is configured
+    ///
+    /// The call that produced the entry already succeeded, so a ledger write
+    /// failure is logged rather than returned.
+    pub(crate) fn record(&self, entry: impl FnOnce() -> LedgerEntry) {
+        if let Some(ledger) = &self.ledger
+            && let Err(e) = ledger.append(&entry())
+        {
+            log::warn!("failed to write ledger {}: {}", ledger.path().display(), e);
+        }
+    }
+
+    /// Whether mutating calls are only logged, see [`Config::with_dry_run`]
+    pub fn is_dry_run(&self) -> bool {
+        self.config.dry_run
+    }
+
+    /// Serialize a request that would be sent in dry-run mode and log it
+    pub(crate) fn log_dry_run<Req>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        request: &Req,
+    ) -> Result<()>
+    where
+        Req: serde::Serialize,
+    {
+        let mut body = serde_json::to_value(request)?;
+        redact(&mut body);
+        log::info!("dry run: {} {} {}", method, self.build_api_url(path), body);
+        Ok(())
+    }
+
+    /// The underlying HTTP client
+    pub(crate) fn http_client(&self) -> &HttpClient {
+        &self.http_client
+    }
+
     /// Create a new client with default configuration
     pub fn with_default_config() -> Result<Self> {
         Self::new(Config::default())
@@ -82,30 +186,118 @@ impl Client {
     }
 
     /// Build the API endpoint URL
-    fn build_api_url(&self, path: &str) -> String {
+    pub(crate) fn build_api_url(&self, path: &str) -> String {
         format!("{}{}", self.config.base_url, path)
     }
 
-    /// Add authorization header if API key is configured
-    fn add_auth_header(
-        &self,
-        req_builder: reqwest::blocking::RequestBuilder,
-    ) -> reqwest::blocking::RequestBuilder {
-        if let Some(api_key) = &self.config.api_key {
-            req_builder.header("Authorization", api_key)
-        } else {
-            req_builder
-        }
+    /// Start a request to an API endpoint with the configured timeout and
+    /// user agent
+    pub(crate) fn request(&self, method: reqwest::Method, path: &str) -> RequestBuilder {
+        self.http_client
+            .request(method, self.build_api_url(path))
+            .timeout(self.config.timeout)
+            .header(reqwest::header::USER_AGENT, &self.config.user_agent)
+    }
+
+    /// Add authorization header if an API key is configured, asking the
+    /// credential provider first
+    pub(crate) fn add_auth_header(&self, req_builder: RequestBuilder) -> Result<RequestBuilder> {
+        let api_key = match &self.config.credentials {
+            Some(provider) => provider.api_key()?,
+            None => self.config.api_key.clone(),
+        };
+
+        let Some(api_key) = api_key else {
+            return Ok(req_builder);
+        };
+
+        let mut value = HeaderValue::from_str(api_key.expose())
+            .map_err(|_| Error::CredentialError("API key is not a valid header value".into()))?;
+        value.set_sensitive(true);
+        Ok(req_builder.header(reqwest::header::AUTHORIZATION, value))
     }
 
     /// Process the request: add auth header, send, and handle response
-    fn process_request<Res>(&self, req_builder: reqwest::blocking::RequestBuilder) -> Result<Res>
+    ///
+    /// Rate limited responses and connection failures are retried up to
+    /// `max_retries` times with exponential backoff, unless the request body
+    /// can't be replayed (such as a file upload). A request rejected as
+    /// unauthorized is retried once after refreshing the credential provider.
+    fn process_request<Res>(
+        &self,
+        telemetry: RequestTelemetry,
+        mut req_builder: RequestBuilder,
+    ) -> Result<Res>
     where
         Res: serde::de::DeserializeOwned,
     {
-        let req_builder = self.add_auth_header(req_builder);
-        let response = req_builder.send()?;
-        self.handle_response::<Res>(response)
+        let mut attempt = 0;
+        let mut refreshed = self.config.credentials.is_none();
+
+        loop {
+            let retry_builder = if attempt < self.config.max_retries || !refreshed {
+                req_builder.try_clone()
+            } else {
+                None
+            };
+
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire();
+            }
+
+            let response = self.add_auth_header(req_builder)?.send();
+            match &response {
+                Ok(response) => telemetry.response(attempt, response),
+                Err(e) => telemetry.failure(attempt, e),
+            }
+
+            if !refreshed
+                && retry_builder.is_some()
+                && let (Ok(response), Some(provider)) = (&response, &self.config.credentials)
+                && response.status() == StatusCode::UNAUTHORIZED
+            {
+                log::debug!("api key rejected, retrying with refreshed credentials");
+                telemetry.retry(attempt, Duration::ZERO, "unauthorized");
+                provider.refresh()?;
+                refreshed = true;
+                req_builder = retry_builder.expect("retry builder is present when retrying");
+                continue;
+            }
+
+            let can_retry = retry_builder.is_some() && attempt < self.config.max_retries;
+            let (delay, reason) = match (response, can_retry) {
+                (Ok(response), true) if response.status() == StatusCode::TOO_MANY_REQUESTS => (
+                    retry_after(response.headers(), SystemTime::now())
+                        .unwrap_or_else(|| self.backoff(attempt))
+                        .min(self.config.max_retry_delay),
+                    "rate limited",
+                ),
+                (Err(e), true) if e.is_connect() => (self.backoff(attempt), "connect error"),
+                (Ok(response), _) => return self.handle_response::<Res>(response),
+                (Err(e), _) => return Err(e.into()),
+            };
+
+            telemetry.retry(attempt, delay, reason);
+            attempt += 1;
+            log::debug!(
+                "retrying request in {:?} (attempt {} of {})",
+                delay,
+                attempt,
+                self.config.max_retries
+            );
+            thread::sleep(delay);
+
+            req_builder = retry_builder.expect("retry builder is present when retrying");
+        }
+    }
+
+    /// Exponential backoff delay before the given retry attempt, capped at
+    /// `max_retry_delay`
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.config
+            .retry_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.config.max_retry_delay)
     }
 
     /// Execute an API request with the given method and body
@@ -119,9 +311,9 @@ impl Client {
         Req: serde::Serialize,
         Res: serde::de::DeserializeOwned,
     {
-        let url = self.build_api_url(path);
-        let req_builder = self.http_client.request(method, &url).json(&request);
-        self.process_request(req_builder)
+        let telemetry = RequestTelemetry::start(&method, path);
+        let req_builder = self.request(method, path).json(&request);
+        self.process_request(telemetry, req_builder)
     }
 
     /// Execute an API request without a request body
@@ -129,9 +321,9 @@ impl Client {
     where
         Res: serde::de::DeserializeOwned,
     {
-        let url = self.build_api_url(path);
-        let req_builder = self.http_client.request(method, &url);
-        self.process_request(req_builder)
+        let telemetry = RequestTelemetry::start(&method, path);
+        let req_builder = self.request(method, path);
+        self.process_request(telemetry, req_builder)
     }
 
     /// Execute an API request with query parameters
@@ -144,9 +336,9 @@ impl Client {
     where
         Res: serde::de::DeserializeOwned,
     {
-        let url = self.build_api_url(path);
-        let req_builder = self.http_client.request(method, &url).query(query);
-        self.process_request(req_builder)
+        let telemetry = RequestTelemetry::start(&method, path);
+        let req_builder = self.request(method, path).query(query);
+        self.process_request(telemetry, req_builder)
     }
 
     /// Execute a multipart API request
@@ -159,9 +351,47 @@ impl Client {
     where
         Res: serde::de::DeserializeOwned,
     {
-        let url = self.build_api_url(path);
-        let req_builder = self.http_client.request(method, &url).multipart(form);
-        self.process_request(req_builder)
+        let telemetry = RequestTelemetry::start(&method, path);
+        let req_builder = self.request(method, path).multipart(form);
+        self.process_request(telemetry, req_builder)
+    }
+}
+
+/// Request fields whose values are never logged
+const REDACTED_FIELDS: &[&str] = &["password", "api_key"];
+
+/// Replace the values of secret fields in a serialized request with `***`
+fn redact(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            for (name, field) in fields.iter_mut() {
+                if REDACTED_FIELDS.contains(&name.as_str()) && !field.is_null() {
+                    *field = "***".into();
+                } else {
+                    redact(field);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+/// Parse the `Retry-After` header of a response, given in seconds or as an
+/// HTTP date; a date in the past means no wait
+fn retry_after(headers: &HeaderMap, now: SystemTime) -> Option<Duration> {
+    let value = headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+
+    match value.parse() {
+        Ok(seconds) => Some(Duration::from_secs(seconds)),
+        Err(_) => {
+            let date = httpdate::parse_http_date(value).ok()?;
+            Some(date.duration_since(now).unwrap_or_default())
+        }
     }
 }
 
@@ -169,6 +399,9 @@ impl Client {
 mod tests {
     use super::*;
     use crate::config::Config;
+    use crate::credentials::CredentialProvider;
+    use crate::test_helpers::helpers::serve;
+    use crate::types::Secret;
 
     #[test]
     fn test_client_creation() {
@@ -188,6 +421,123 @@ mod tests {
         assert!(!client.is_valid_url(""));
     }
 
+    #[test]
+    fn test_backoff_is_capped() {
+        let config = Config::default()
+            .with_retry_backoff(Duration::from_secs(1))
+            .with_max_retry_delay(Duration::from_secs(5));
+        let client = Client::new(config).unwrap();
+
+        assert_eq!(client.backoff(2), Duration::from_secs(4));
+        assert_eq!(client.backoff(3), Duration::from_secs(5));
+        assert_eq!(client.backoff(40), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_retry_after_seconds_and_dates() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_800_000_000);
+        let headers = |value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                reqwest::header::RETRY_AFTER,
+                HeaderValue::from_str(value).unwrap(),
+            );
+            headers
+        };
+
+        assert_eq!(
+            retry_after(&headers(" 120 "), now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            retry_after(
+                &headers(&httpdate::fmt_http_date(now + Duration::from_secs(30))),
+                now
+            ),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            retry_after(
+                &headers(&httpdate::fmt_http_date(now - Duration::from_secs(30))),
+                now
+            ),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(retry_after(&headers("soon"), now), None);
+        assert_eq!(retry_after(&HeaderMap::new(), now), None);
+    }
+
+    #[test]
+    fn test_backoff_doubles() {
+        let config = Config::default().with_retry_backoff(Duration::from_millis(100));
+        let client = Client::new(config).unwrap();
+
+        assert_eq!(client.backoff(0), Duration::from_millis(100));
+        assert_eq!(client.backoff(1), Duration::from_millis(200));
+        assert_eq!(client.backoff(3), Duration::from_millis(800));
+    }
+
+    #[test]
+    fn test_rate_limiter_spaces_requests() {
+        let limiter = RateLimiter::new(100);
+        let start = Instant::now();
+
+        for _ in 0..4 {
+            limiter.acquire();
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(30));
+    }
+
+    /// Hands out keys in order, moving to the next one on refresh
+    #[derive(Debug)]
+    struct RotatingCredentials(Mutex<Vec<&'static str>>);
+
+    impl CredentialProvider for RotatingCredentials {
+        fn api_key(&self) -> Result<Option<Secret>> {
+            Ok(self.0.lock().unwrap().first().map(|key| Secret::new(*key)))
+        }
+
+        fn refresh(&self) -> Result<()> {
+            self.0.lock().unwrap().remove(0);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_unauthorized_retries_with_refreshed_key() {
+        let (base_url, server) = serve(vec![
+            "HTTP/1.1 401 Unauthorized\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}",
+        ]);
+
+        let config = Config::new(base_url)
+            .with_max_retries(0)
+            .with_credentials(RotatingCredentials(Mutex::new(vec!["old", "new"])));
+        let client = Client::new(config).unwrap();
+
+        let response: serde_json::Value = client
+            .execute_request_no_body(reqwest::Method::GET, "/domains")
+            .unwrap();
+        assert_eq!(response, serde_json::json!({}));
+        assert_eq!(server.join().unwrap(), ["old", "new"]);
+    }
+
+    #[test]
+    fn test_secrets_are_redacted() {
+        let client = Client::new(Config::default().with_api_key("hunter2")).unwrap();
+        assert!(!format!("{:?}", client).contains("hunter2"));
+
+        let mut body = serde_json::json!({
+            "target_url": "https://example.com/",
+            "password": "hunter2",
+            "nested": [{"api_key": "hunter2"}],
+        });
+        redact(&mut body);
+        assert!(!body.to_string().contains("hunter2"));
+        assert_eq!(body["target_url"], "https://example.com/");
+    }
+
     #[test]
     fn test_api_url_building() {
         let config = Config::new("https://api.example.com");
@@ -9,9 +9,56 @@
  * File Created: 2025-10-23 11:28:59
  *
  * Modified By: S.EE Development Team <dev@s.ee>
- * Last Modified: 2025-12-04 19:24:47
+ * Last Modified: 2026-10-20 07:44:22
  */
 
+//! Client configuration.
+//!
+//! A [`Config`] can be built in code, read from the environment with
+//! [`Config::from_env`], or read from a profile of a TOML file with
+//! [`Config::from_file`]. When sources are combined, later ones win:
+//!
+//! 1. built-in defaults
+//! 2. the config file profile
+//! 3. environment variables, applied with [`Config::with_env`]
+//! 4. `with_*` calls in code
+//!
+//! ```no_run
+//! use see_sdk::config::Config;
+//! use std::time::Duration;
+//!
+//! let config = Config::from_file("see.toml", "work")?
+//!     .with_env()?
+//!     .with_timeout(Duration::from_secs(10));
+//! # Ok::<(), see_sdk::error::Error>(())
+//! ```
+//!
+//! A config file holds one table per profile:
+//!
+//! ```toml
+//! [profiles.default]
+//! api_key = "your-api-key"
+//!
+//! [profiles.work]
+//! api_key = "another-api-key"
+//! base_url = "https://s.ee/api/v1"
+//! timeout = 10
+//! max_retries = 5
+//! max_retry_delay = 30
+//! default_domain = "example.com"
+//! ```
+
+use crate::credentials::CredentialProvider;
+use crate::error::{Error, Result};
+use crate::types::{DomainName, Secret};
+use reqwest::blocking::Client as HttpClient;
+use reqwest::{Certificate, Identity, Proxy};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Default base URL for the API
@@ -26,17 +73,62 @@ pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
 /// Default maximum retry attempts
 pub const DEFAULT_MAX_RETRIES: u32 = 3;
 
+/// Default delay before the first retry, doubled on each further attempt
+pub const DEFAULT_RETRY_BACKOFF_MILLIS: u64 = 500;
+
+/// Default longest wait before a retry, in seconds
+pub const DEFAULT_MAX_RETRY_DELAY_SECS: u64 = 60;
+
 /// Default domain for shortened URLs
 pub const DEFAULT_DOMAIN: &str = "s.ee";
 
+/// Name of the profile used when none is given
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Environment variable holding the API key
+pub const ENV_API_KEY: &str = "SEE_API_KEY";
+
+/// Environment variable holding the base URL
+pub const ENV_BASE_URL: &str = "SEE_BASE_URL";
+
+/// Environment variable holding the request timeout in seconds
+pub const ENV_TIMEOUT: &str = "SEE_TIMEOUT";
+
+/// Environment variable holding the maximum number of retries
+pub const ENV_MAX_RETRIES: &str = "SEE_MAX_RETRIES";
+
+/// Environment variable holding the default domain
+pub const ENV_DEFAULT_DOMAIN: &str = "SEE_DEFAULT_DOMAIN";
+
 /// Client configuration for content sharing
 #[derive(Debug, Clone)]
 pub struct Config {
     pub base_url: String,
-    pub api_key: Option<String>,
+    pub api_key: Option<Secret>,
     pub timeout: Duration,
     pub user_agent: String,
     pub max_retries: u32,
+    pub retry_backoff: Duration,
+    /// Longest wait before a retry, capping both the backoff and the
+    /// server's `Retry-After`
+    pub max_retry_delay: Duration,
+    /// Maximum number of requests per second sent by a client and its clones
+    pub rate_limit: Option<u32>,
+    /// Path of the JSON-lines ledger recording created resources
+    pub ledger_path: Option<PathBuf>,
+    /// Log mutating requests and return synthetic responses instead of
+    /// sending them
+    pub dry_run: bool,
+    /// Domain used by requests that don't name one; when unset, links use
+    /// [`DEFAULT_DOMAIN`] and the service picks for texts
+    pub default_domain: Option<DomainName>,
+    /// Source of the API key consulted on every request, taking precedence
+    /// over `api_key`
+    pub credentials: Option<Arc<dyn CredentialProvider>>,
+    /// Settings of the HTTP client built by `Client::new`
+    pub http: HttpOptions,
+    /// Pre-built HTTP client used instead of building one from `http`
+    pub http_client: Option<HttpClient>,
 }
 
 impl Default for Config {
@@ -47,6 +139,15 @@ impl Default for Config {
             timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
             user_agent: DEFAULT_USER_AGENT.to_string(),
             max_retries: DEFAULT_MAX_RETRIES,
+            retry_backoff: Duration::from_millis(DEFAULT_RETRY_BACKOFF_MILLIS),
+            max_retry_delay: Duration::from_secs(DEFAULT_MAX_RETRY_DELAY_SECS),
+            rate_limit: None,
+            ledger_path: None,
+            dry_run: false,
+            default_domain: None,
+            credentials: None,
+            http: HttpOptions::default(),
+            http_client: None,
         }
     }
 }
@@ -61,11 +162,17 @@ impl Config {
     }
 
     /// Set the API key for authentication
-    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+    pub fn with_api_key(mut self, api_key: impl Into<Secret>) -> Self {
         self.api_key = Some(api_key.into());
         self
     }
 
+    /// Read the API key from a provider on every request
+    pub fn with_credentials(mut self, provider: impl CredentialProvider + 'static) -> Self {
+        self.credentials = Some(Arc::new(provider));
+        self
+    }
+
     /// Set the request timeout
     pub fn with_timeout(mut self, timeout: Duration) -> Self {
         self.timeout = timeout;
@@ -83,6 +190,370 @@ impl Config {
         self.max_retries = max_retries;
         self
     }
+
+    /// Set the delay before the first retry
+    pub fn with_retry_backoff(mut self, retry_backoff: Duration) -> Self {
+        self.retry_backoff = retry_backoff;
+        self
+    }
+
+    /// Set the longest wait before a retry
+    pub fn with_max_retry_delay(mut self, max_retry_delay: Duration) -> Self {
+        self.max_retry_delay = max_retry_delay;
+        self
+    }
+
+    /// Record every create, update and delete in a ledger file
+    pub fn with_ledger(mut self, path: impl Into<PathBuf>) -> Self {
+        self.ledger_path = Some(path.into());
+        self
+    }
+
+    /// Enable or disable dry-run mode
+    ///
+    /// In dry-run mode, calls that create, update or delete resources are
+    /// validated and logged but never sent.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Limit the number of requests sent per second
+    pub fn with_rate_limit(mut self, requests_per_second: u32) -> Self {
+        self.rate_limit = Some(requests_per_second);
+        self
+    }
+
+    /// Set the options of the HTTP client
+    pub fn with_http_options(mut self, http: HttpOptions) -> Self {
+        self.http = http;
+        self
+    }
+
+    /// Send requests through a pre-built HTTP client
+    ///
+    /// The client's own settings apply, except for the timeout and user
+    /// agent, which are set per request; `http` options are ignored.
+    pub fn with_http_client(mut self, http_client: HttpClient) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Set the domain used by requests that don't name one
+    pub fn with_default_domain(mut self, domain: DomainName) -> Self {
+        self.default_domain = Some(domain);
+        self
+    }
+
+    /// Create a configuration from the `SEE_*` environment variables
+    ///
+    /// See [`with_env`](Self::with_env) for the variables read.
+    pub fn from_env() -> Result<Self> {
+        Self::default().with_env()
+    }
+
+    /// Override settings with the environment variables that are set
+    ///
+    /// Reads `SEE_API_KEY`, `SEE_BASE_URL`, `SEE_TIMEOUT` (seconds),
+    /// `SEE_MAX_RETRIES` and `SEE_DEFAULT_DOMAIN`. Empty variables are
+    /// ignored.
+    pub fn with_env(self) -> Result<Self> {
+        self.with_vars(|name| env::var(name).ok())
+    }
+
+    /// Create a configuration from a profile of a TOML config file
+    pub fn from_file(path: impl AsRef<Path>, profile: &str) -> Result<Self> {
+        ConfigFile::load(path)?.profile(profile)
+    }
+
+    /// Apply variables looked up by `var`, so parsing can be tested without
+    /// touching the process environment
+    fn with_vars(mut self, var: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let var = |name: &str| var(name).filter(|value| !value.trim().is_empty());
+
+        if let Some(api_key) = var(ENV_API_KEY) {
+            self.api_key = Some(Secret::new(api_key));
+        }
+        if let Some(base_url) = var(ENV_BASE_URL) {
+            self.base_url = base_url;
+        }
+        if let Some(timeout) = var(ENV_TIMEOUT) {
+            self.timeout = Duration::from_secs(parse_var(ENV_TIMEOUT, &timeout)?);
+        }
+        if let Some(max_retries) = var(ENV_MAX_RETRIES) {
+            self.max_retries = parse_var(ENV_MAX_RETRIES, &max_retries)?;
+        }
+        if let Some(domain) = var(ENV_DEFAULT_DOMAIN) {
+            self.default_domain = Some(parse_var(ENV_DEFAULT_DOMAIN, &domain)?);
+        }
+
+        Ok(self)
+    }
+}
+
+/// Parse the value of an environment variable, naming it in the error
+fn parse_var<T>(name: &str, value: &str) -> Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    value
+        .trim()
+        .parse()
+        .map_err(|e| Error::ConfigError(format!("{}={:?}: {}", name, value, e)))
+}
+
+/// A client certificate for mutual TLS
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClientIdentity {
+    /// A DER-encoded PKCS#12 archive and its password
+    Pkcs12 { path: PathBuf, password: Secret },
+
+    /// A PEM certificate chain and a PEM PKCS#8 private key
+    Pem {
+        cert_path: PathBuf,
+        key_path: PathBuf,
+    },
+}
+
+impl ClientIdentity {
+    fn load(&self) -> Result<Identity> {
+        Ok(match self {
+            ClientIdentity::Pkcs12 { path, password } => {
+                Identity::from_pkcs12_der(&fs::read(path)?, password.expose())?
+            }
+            ClientIdentity::Pem {
+                cert_path,
+                key_path,
+            } => Identity::from_pkcs8_pem(&fs::read(cert_path)?, &fs::read(key_path)?)?,
+        })
+    }
+}
+
+/// Settings of the underlying HTTP client; unset fields keep reqwest's
+/// defaults
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HttpOptions {
+    /// Proxy for all requests, such as `http://proxy:8080` or
+    /// `socks5://proxy:1080`; the `HTTP(S)_PROXY` variables apply otherwise
+    pub proxy: Option<String>,
+    /// Hosts that bypass the proxy, in `NO_PROXY` format
+    pub no_proxy: Option<String>,
+    /// PEM files of extra root certificates to trust
+    pub root_certificates: Vec<PathBuf>,
+    /// Client certificate for mutual TLS
+    pub identity: Option<ClientIdentity>,
+    /// Timeout for establishing a connection, separate from the request
+    /// timeout
+    pub connect_timeout: Option<Duration>,
+    /// How long idle connections are kept in the pool
+    pub pool_idle_timeout: Option<Duration>,
+    /// Maximum number of idle connections kept per host
+    pub pool_max_idle_per_host: Option<usize>,
+    /// Speak HTTP/2 without negotiating it first
+    pub http2_prior_knowledge: bool,
+}
+
+impl HttpOptions {
+    /// Create options with reqwest's defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send all requests through a proxy
+    pub fn with_proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+
+    /// Bypass the proxy for the given hosts, in `NO_PROXY` format
+    pub fn with_no_proxy(mut self, no_proxy: impl Into<String>) -> Self {
+        self.no_proxy = Some(no_proxy.into());
+        self
+    }
+
+    /// Trust the root certificates in a PEM file
+    pub fn with_root_certificate(mut self, path: impl Into<PathBuf>) -> Self {
+        self.root_certificates.push(path.into());
+        self
+    }
+
+    /// Present a client certificate
+    pub fn with_identity(mut self, identity: ClientIdentity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    /// Set the timeout for establishing a connection
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Set how long idle connections are kept in the pool
+    pub fn with_pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the maximum number of idle connections kept per host
+    pub fn with_pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Speak HTTP/2 without negotiating it first
+    pub fn with_http2_prior_knowledge(mut self, enabled: bool) -> Self {
+        self.http2_prior_knowledge = enabled;
+        self
+    }
+
+    /// Build an HTTP client with these options
+    ///
+    /// The client can be shared by several [`Client`](crate::client::Client)s
+    /// through [`Config::with_http_client`].
+    pub fn build_client(&self) -> Result<HttpClient> {
+        let mut builder = HttpClient::builder();
+
+        if let Some(proxy) = &self.proxy {
+            let no_proxy = self
+                .no_proxy
+                .as_deref()
+                .and_then(reqwest::NoProxy::from_string);
+            builder = builder.proxy(Proxy::all(proxy)?.no_proxy(no_proxy));
+        }
+
+        for path in &self.root_certificates {
+            for certificate in Certificate::from_pem_bundle(&fs::read(path)?)? {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+
+        if let Some(identity) = &self.identity {
+            builder = builder.identity(identity.load()?);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if self.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+
+        Ok(builder.build()?)
+    }
+}
+
+/// Settings of a single config file profile; unset fields keep the defaults
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileConfig {
+    pub api_key: Option<Secret>,
+    pub base_url: Option<String>,
+    /// Request timeout in seconds
+    pub timeout: Option<u64>,
+    pub user_agent: Option<String>,
+    pub max_retries: Option<u32>,
+    /// Longest wait before a retry in seconds
+    pub max_retry_delay: Option<u64>,
+    pub rate_limit: Option<u32>,
+    pub ledger_path: Option<PathBuf>,
+    pub default_domain: Option<DomainName>,
+    /// Proxy for all requests
+    pub proxy: Option<String>,
+    /// Hosts that bypass the proxy
+    pub no_proxy: Option<String>,
+    /// PEM files of extra root certificates to trust
+    #[serde(default)]
+    pub root_certificates: Vec<PathBuf>,
+    /// Connect timeout in seconds
+    pub connect_timeout: Option<u64>,
+}
+
+impl ProfileConfig {
+    /// Build a configuration from the defaults and this profile
+    pub fn to_config(&self) -> Result<Config> {
+        let mut config = Config::default();
+
+        if let Some(api_key) = &self.api_key {
+            config.api_key = Some(api_key.clone());
+        }
+        if let Some(base_url) = &self.base_url {
+            config.base_url = base_url.clone();
+        }
+        if let Some(timeout) = self.timeout {
+            config.timeout = Duration::from_secs(timeout);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            config.user_agent = user_agent.clone();
+        }
+        if let Some(max_retries) = self.max_retries {
+            config.max_retries = max_retries;
+        }
+        if let Some(max_retry_delay) = self.max_retry_delay {
+            config.max_retry_delay = Duration::from_secs(max_retry_delay);
+        }
+        config.rate_limit = self.rate_limit;
+        config.ledger_path = self.ledger_path.clone();
+        config.http.proxy = self.proxy.clone();
+        config.http.no_proxy = self.no_proxy.clone();
+        config.http.root_certificates = self.root_certificates.clone();
+        config.http.connect_timeout = self.connect_timeout.map(Duration::from_secs);
+
+        if let Some(domain) = &self.default_domain {
+            // Domains are deserialized unchecked
+            config.default_domain = Some(DomainName::new(domain.as_str())?);
+        }
+
+        Ok(config)
+    }
+}
+
+/// A TOML config file with named profiles under `[profiles.<name>]`
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigFile {
+    #[serde(default)]
+    pub profiles: BTreeMap<String, ProfileConfig>,
+}
+
+impl ConfigFile {
+    /// Read and parse a config file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .map_err(|e| Error::ConfigError(format!("cannot read {}: {}", path.display(), e)))?;
+
+        content
+            .parse()
+            .map_err(|e| Error::ConfigError(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Names of the profiles in the file, sorted
+    pub fn profile_names(&self) -> impl Iterator<Item = &str> {
+        self.profiles.keys().map(String::as_str)
+    }
+
+    /// Build the configuration of a profile
+    pub fn profile(&self, name: &str) -> Result<Config> {
+        self.profiles
+            .get(name)
+            .ok_or_else(|| Error::ConfigError(format!("no profile named {:?}", name)))?
+            .to_config()
+    }
+}
+
+impl std::str::FromStr for ConfigFile {
+    type Err = toml::de::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        toml::from_str(s)
+    }
 }
 
 #[cfg(test)]
@@ -97,7 +568,14 @@ mod tests {
         assert_eq!(config.timeout, Duration::from_secs(DEFAULT_TIMEOUT_SECS));
         assert_eq!(config.user_agent, DEFAULT_USER_AGENT);
         assert_eq!(config.max_retries, DEFAULT_MAX_RETRIES);
+        assert_eq!(
+            config.retry_backoff,
+            Duration::from_millis(DEFAULT_RETRY_BACKOFF_MILLIS)
+        );
         assert!(config.api_key.is_none());
+        assert!(config.rate_limit.is_none());
+        assert!(!config.dry_run);
+        assert!(config.default_domain.is_none());
     }
 
     #[test]
@@ -126,4 +604,108 @@ mod tests {
         // Other fields should remain default
         assert_eq!(config.base_url, DEFAULT_BASE_URL);
     }
+
+    #[test]
+    fn test_config_from_vars() -> Result<()> {
+        let vars = |name: &str| match name {
+            ENV_API_KEY => Some("env_key".to_string()),
+            ENV_TIMEOUT => Some(" 12 ".to_string()),
+            ENV_MAX_RETRIES => Some(String::new()),
+            ENV_DEFAULT_DOMAIN => Some("Example.COM".to_string()),
+            _ => None,
+        };
+
+        let config = Config::default().with_max_retries(7).with_vars(vars)?;
+        assert_eq!(config.api_key.unwrap(), "env_key");
+        assert_eq!(config.timeout, Duration::from_secs(12));
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+        // Empty variables are ignored
+        assert_eq!(config.max_retries, 7);
+        assert_eq!(config.default_domain.unwrap(), "example.com");
+
+        let invalid = Config::default()
+            .with_vars(|name| (name == ENV_MAX_RETRIES).then(|| "many".to_string()));
+        assert!(matches!(invalid, Err(Error::ConfigError(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_file_profiles() -> Result<()> {
+        let file: ConfigFile = r#"
+            [profiles.default]
+            api_key = "default_key"
+
+            [profiles.work]
+            api_key = "work_key"
+            base_url = "https://work.example/api/v1"
+            timeout = 5
+            max_retry_delay = 10
+            default_domain = "example.com"
+        "#
+        .parse()
+        .unwrap();
+
+        assert_eq!(
+            file.profile_names().collect::<Vec<_>>(),
+            ["default", "work"]
+        );
+
+        let config = file.profile(DEFAULT_PROFILE)?;
+        assert_eq!(config.api_key.unwrap(), "default_key");
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+
+        let config = file.profile("work")?;
+        assert_eq!(config.base_url, "https://work.example/api/v1");
+        assert_eq!(config.timeout, Duration::from_secs(5));
+        assert_eq!(config.max_retries, DEFAULT_MAX_RETRIES);
+        assert_eq!(config.max_retry_delay, Duration::from_secs(10));
+        assert_eq!(config.default_domain.unwrap(), "example.com");
+
+        assert!(matches!(
+            file.profile("missing"),
+            Err(Error::ConfigError(_))
+        ));
+        assert!("[profiles.x]\ntimeot = 5".parse::<ConfigFile>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_http_options_build_client() {
+        let options = HttpOptions::new()
+            .with_proxy("socks5://127.0.0.1:1080")
+            .with_no_proxy("localhost,.internal")
+            .with_connect_timeout(Duration::from_secs(2))
+            .with_pool_idle_timeout(Duration::from_secs(30))
+            .with_pool_max_idle_per_host(4)
+            .with_http2_prior_knowledge(true);
+        assert!(options.build_client().is_ok());
+
+        let invalid_proxy = HttpOptions::new().with_proxy("not a proxy");
+        assert!(matches!(
+            invalid_proxy.build_client(),
+            Err(Error::HttpError(_))
+        ));
+
+        let missing_ca = HttpOptions::new().with_root_certificate("/nonexistent/ca.pem");
+        assert!(matches!(missing_ca.build_client(), Err(Error::IoError(_))));
+    }
+
+    #[test]
+    fn test_config_file_http_options() -> Result<()> {
+        let file: ConfigFile = r#"
+            [profiles.corporate]
+            proxy = "http://proxy.internal:8080"
+            connect_timeout = 3
+        "#
+        .parse()
+        .unwrap();
+
+        let config = file.profile("corporate")?;
+        assert_eq!(
+            config.http.proxy.as_deref(),
+            Some("http://proxy.internal:8080")
+        );
+        assert_eq!(config.http.connect_timeout, Some(Duration::from_secs(3)));
+        Ok(())
+    }
 }
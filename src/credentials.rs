@@ -0,0 +1,285 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: credentials.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-19 21:52:40
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 08:49:31
+ */
+
+//! Sources of the API key, consulted on every request so keys can be rotated
+//! without rebuilding the [`Client`](crate::client::Client).
+//!
+//! Set a provider with
+//! [`Config::with_credentials`](crate::config::Config::with_credentials).
+//! When the server rejects a key, the client calls
+//! [`CredentialProvider::refresh`] and retries the request once.
+
+use crate::config::ENV_API_KEY;
+use crate::error::{Error, Result};
+use crate::types::Secret;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::{Mutex, MutexGuard};
+use std::time::SystemTime;
+use zeroize::Zeroize;
+
+/// A source of the API key
+pub trait CredentialProvider: Send + Sync + fmt::Debug {
+    /// The API key to send with the next request, `None` to send none
+    fn api_key(&self) -> Result<Option<Secret>>;
+
+    /// Forget any cached key after the server rejected it
+    fn refresh(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// A fixed API key
+#[derive(Debug, Clone)]
+pub struct StaticCredentials {
+    api_key: Secret,
+}
+
+impl StaticCredentials {
+    /// Create a provider that always returns `api_key`
+    pub fn new(api_key: impl Into<Secret>) -> Self {
+        Self {
+            api_key: api_key.into(),
+        }
+    }
+}
+
+impl CredentialProvider for StaticCredentials {
+    fn api_key(&self) -> Result<Option<Secret>> {
+        Ok(Some(self.api_key.clone()))
+    }
+}
+
+/// The API key in an environment variable, read on every request
+#[derive(Debug, Clone)]
+pub struct EnvCredentials {
+    var: String,
+}
+
+impl Default for EnvCredentials {
+    fn default() -> Self {
+        Self::new(ENV_API_KEY)
+    }
+}
+
+impl EnvCredentials {
+    /// Read the key from the given variable
+    pub fn new(var: impl Into<String>) -> Self {
+        Self { var: var.into() }
+    }
+}
+
+impl CredentialProvider for EnvCredentials {
+    fn api_key(&self) -> Result<Option<Secret>> {
+        Ok(std::env::var(&self.var)
+            .ok()
+            .filter(|key| !key.trim().is_empty())
+            .map(Secret::new))
+    }
+}
+
+/// The API key in a file, read again whenever the file changes
+#[derive(Debug)]
+pub struct FileCredentials {
+    path: PathBuf,
+    cache: Mutex<Option<(SystemTime, Secret)>>,
+}
+
+impl FileCredentials {
+    /// Read the key from the file at `path`, ignoring surrounding whitespace
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            cache: Mutex::new(None),
+        }
+    }
+
+    fn cache(&self) -> MutexGuard<'_, Option<(SystemTime, Secret)>> {
+        self.cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl CredentialProvider for FileCredentials {
+    fn api_key(&self) -> Result<Option<Secret>> {
+        let modified = fs::metadata(&self.path)?.modified()?;
+
+        let mut cache = self.cache();
+        if let Some((cached_at, key)) = cache.as_ref()
+            && *cached_at == modified
+        {
+            return Ok(Some(key.clone()));
+        }
+
+        let content = Secret::new(fs::read_to_string(&self.path)?);
+        let key = Secret::new(content.expose().trim());
+        if key.expose().is_empty() {
+            return Err(Error::CredentialError(format!(
+                "{} is empty",
+                self.path.display()
+            )));
+        }
+
+        *cache = Some((modified, key.clone()));
+        Ok(Some(key))
+    }
+
+    fn refresh(&self) -> Result<()> {
+        *self.cache() = None;
+        Ok(())
+    }
+}
+
+/// The API key printed by a command, such as `pass show see/api-key`
+///
+/// The command runs on first use and again after each refresh.
+#[derive(Debug)]
+pub struct CommandCredentials {
+    program: String,
+    args: Vec<String>,
+    cache: Mutex<Option<Secret>>,
+}
+
+impl CommandCredentials {
+    /// Run `program` without arguments
+    pub fn new(program: impl Into<String>) -> Self {
+        Self {
+            program: program.into(),
+            args: Vec::new(),
+            cache: Mutex::new(None),
+        }
+    }
+
+    /// Add an argument
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Add arguments
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    fn cache(&self) -> MutexGuard<'_, Option<Secret>> {
+        self.cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Run the command and return the first line of its output
+    fn run(&self) -> Result<Secret> {
+        let mut output = Command::new(&self.program).args(&self.args).output()?;
+
+        if !output.status.success() {
+            return Err(Error::CredentialError(format!(
+                "{} failed with {}: {}",
+                self.program,
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        let key = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .next()
+            .map(str::trim)
+            .filter(|key| !key.is_empty())
+            .map(Secret::new);
+        output.stdout.zeroize();
+
+        key.ok_or_else(|| Error::CredentialError(format!("{} printed no key", self.program)))
+    }
+}
+
+impl CredentialProvider for CommandCredentials {
+    fn api_key(&self) -> Result<Option<Secret>> {
+        let mut cache = self.cache();
+        if cache.is_none() {
+            *cache = Some(self.run()?);
+        }
+
+        Ok(cache.clone())
+    }
+
+    fn refresh(&self) -> Result<()> {
+        *self.cache() = None;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::time::Duration;
+
+    #[test]
+    fn test_static_credentials_hide_key() -> Result<()> {
+        let credentials = StaticCredentials::new("secret");
+
+        assert_eq!(credentials.api_key()?.unwrap(), "secret");
+        assert!(!format!("{:?}", credentials).contains("secret"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_env_credentials_unset() -> Result<()> {
+        let credentials = EnvCredentials::new("SEE_SDK_TEST_UNSET_API_KEY");
+        assert_eq!(credentials.api_key()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_file_credentials_reload_on_change() -> Result<()> {
+        let mut path = env::temp_dir();
+        path.push(format!("see-sdk-credentials-{}", std::process::id()));
+        fs::write(&path, "first-key\n")?;
+
+        let credentials = FileCredentials::new(&path);
+        assert_eq!(credentials.api_key()?.unwrap(), "first-key");
+
+        fs::write(&path, "second-key\n")?;
+        fs::File::options()
+            .write(true)
+            .open(&path)?
+            .set_modified(SystemTime::now() + Duration::from_secs(60))?;
+        assert_eq!(credentials.api_key()?.unwrap(), "second-key");
+
+        fs::write(&path, "")?;
+        credentials.refresh()?;
+        assert!(matches!(
+            credentials.api_key(),
+            Err(Error::CredentialError(_))
+        ));
+
+        let _ = fs::remove_file(path);
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_command_credentials() -> Result<()> {
+        let credentials = CommandCredentials::new("echo").arg("command-key");
+        assert_eq!(credentials.api_key()?.unwrap(), "command-key");
+
+        let failing = CommandCredentials::new("false");
+        assert!(matches!(failing.api_key(), Err(Error::CredentialError(_))));
+        Ok(())
+    }
+}
@@ -0,0 +1,584 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: doctor.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-20 04:10:26
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 07:57:26
+ */
+
+//! Connectivity and credential diagnostics.
+//!
+//! [`diagnose`] walks from the configuration down to an authenticated call
+//! and reports each step as a [`Check`], with a hint when something is off.
+//! A step that depends on a failed one is skipped, so the first failure is
+//! the one to fix.
+
+use crate::client::Client;
+use crate::error::Error;
+use reqwest::StatusCode;
+use reqwest::blocking::Response;
+use reqwest::header::HeaderMap;
+use serde::Serialize;
+use std::fmt;
+use std::net::ToSocketAddrs;
+use std::time::{Duration, Instant, SystemTime};
+use url::Url;
+
+/// Endpoint used for the authenticated call, cheap and side-effect free
+const PROBE_PATH: &str = "/domains";
+
+/// Latency above which the authenticated call is reported as slow
+pub const SLOW_LATENCY: Duration = Duration::from_secs(2);
+
+/// Clock difference to the service above which a warning is reported
+pub const MAX_CLOCK_SKEW: Duration = Duration::from_secs(30);
+
+/// Checks that run after `config`, in order
+const AFTER_CONFIG: [&str; 6] = ["dns", "tls", "auth", "latency", "rate_limit", "clock"];
+
+/// Remaining share of the rate limit below which a warning is reported,
+/// in percent
+const LOW_RATE_LIMIT_PERCENT: u64 = 10;
+
+/// Outcome of a check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Ok,
+    Warning,
+    Failed,
+
+    /// Not run, because an earlier check failed or it doesn't apply
+    Skipped,
+}
+
+impl fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CheckStatus::Ok => "ok",
+            CheckStatus::Warning => "warning",
+            CheckStatus::Failed => "failed",
+            CheckStatus::Skipped => "skipped",
+        })
+    }
+}
+
+/// A single diagnostic step
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Check {
+    /// Short name, such as `dns` or `auth`
+    pub name: &'static str,
+
+    pub status: CheckStatus,
+
+    /// What was found
+    pub detail: String,
+
+    /// What to do about it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+}
+
+impl Check {
+    fn new(name: &'static str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn ok(name: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Ok, detail)
+    }
+
+    fn warning(name: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Warning, detail)
+    }
+
+    fn failed(name: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Failed, detail)
+    }
+
+    fn skipped(name: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Skipped, detail)
+    }
+
+    fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+}
+
+/// All checks, in the order they ran
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Diagnosis {
+    pub checks: Vec<Check>,
+}
+
+impl Diagnosis {
+    /// Whether no check failed
+    pub fn is_healthy(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    /// The failed checks
+    pub fn failures(&self) -> impl Iterator<Item = &Check> {
+        self.checks
+            .iter()
+            .filter(|check| check.status == CheckStatus::Failed)
+    }
+
+    fn push(&mut self, check: Check) -> CheckStatus {
+        let status = check.status;
+        self.checks.push(check);
+        status
+    }
+
+    fn skip(&mut self, names: &[&'static str], reason: &str) {
+        for name in names {
+            self.push(Check::skipped(name, reason));
+        }
+    }
+}
+
+impl fmt::Display for Diagnosis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for check in &self.checks {
+            writeln!(f, "[{}] {}: {}", check.status, check.name, check.detail)?;
+            if let Some(hint) = &check.hint {
+                writeln!(f, "    hint: {}", hint)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Run every check against the client's configuration and service
+///
+/// Makes at most two requests: an unauthenticated one to check TLS and an
+/// authenticated `GET /domains`. Neither is retried or rate limited, and
+/// both are sent in dry-run mode too.
+pub fn diagnose(client: &Client) -> Diagnosis {
+    let mut diagnosis = Diagnosis::default();
+
+    let base_url = match check_config(client, &mut diagnosis) {
+        Some(url) => url,
+        None => {
+            diagnosis.skip(&AFTER_CONFIG, "configuration is invalid");
+            return diagnosis;
+        }
+    };
+
+    if diagnosis.push(check_dns(client, &base_url)) == CheckStatus::Failed {
+        diagnosis.skip(&AFTER_CONFIG[1..], "host name does not resolve");
+        return diagnosis;
+    }
+
+    if diagnosis.push(check_tls(client, &base_url)) == CheckStatus::Failed {
+        diagnosis.skip(&AFTER_CONFIG[2..], "no connection to the service");
+        return diagnosis;
+    }
+
+    let started = Instant::now();
+    let response = client
+        .add_auth_header(
+            client
+                .request(reqwest::Method::GET, PROBE_PATH)
+                .header(reqwest::header::ACCEPT, "application/json"),
+        )
+        .and_then(|request| Ok(request.send()?));
+    let latency = started.elapsed();
+
+    let response = match response {
+        Ok(response) => response,
+        Err(e) => {
+            diagnosis.push(
+                Check::failed("auth", format!("request failed: {}", e)).with_hint(request_hint(&e)),
+            );
+            diagnosis.skip(&AFTER_CONFIG[3..], "the authenticated call failed");
+            return diagnosis;
+        }
+    };
+
+    let headers = response.headers().clone();
+    diagnosis.push(check_auth(response));
+    diagnosis.push(check_latency(client, latency));
+    diagnosis.push(check_rate_limit(&headers));
+    diagnosis.push(check_clock(&headers, SystemTime::now()));
+
+    diagnosis
+}
+
+/// Report a configuration or client that could not be built as a failed
+/// `config` check, skipping the others
+///
+/// Use this when loading the configuration or creating the [`Client`]
+/// fails, so the failure is still diagnosed with a hint.
+pub fn config_failure(error: &Error) -> Diagnosis {
+    let hint = match error {
+        Error::ConfigError(_) => {
+            "check the SEE_* environment variables and the config file profile".to_string()
+        }
+        Error::IoError(_) => {
+            "check that the certificate and ledger files exist and are readable".to_string()
+        }
+        other => request_hint(other),
+    };
+
+    let mut diagnosis = Diagnosis::default();
+    diagnosis.push(Check::failed("config", error.to_string()).with_hint(hint));
+    diagnosis.skip(&AFTER_CONFIG, "configuration is invalid");
+    diagnosis
+}
+
+/// Validate the configuration, returning the parsed base URL if it is usable
+fn check_config(client: &Client, diagnosis: &mut Diagnosis) -> Option<Url> {
+    let config = client.config();
+
+    let base_url = match Url::parse(&config.base_url) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") && url.host_str().is_some() => url,
+        Ok(url) => {
+            diagnosis.push(
+                Check::failed("config", format!("base URL {} is not an HTTP URL", url))
+                    .with_hint("set base_url to the API root, such as https://s.ee/api/v1"),
+            );
+            return None;
+        }
+        Err(e) => {
+            diagnosis.push(
+                Check::failed(
+                    "config",
+                    format!("base URL {:?} is invalid: {}", config.base_url, e),
+                )
+                .with_hint("set base_url to the API root, such as https://s.ee/api/v1"),
+            );
+            return None;
+        }
+    };
+
+    let api_key = match &config.credentials {
+        Some(provider) => provider.api_key(),
+        None => Ok(config.api_key.clone()),
+    };
+    let check = match api_key {
+        Ok(Some(_)) if config.timeout.is_zero() => {
+            Check::warning("config", "request timeout is zero")
+                .with_hint("set timeout to a few seconds")
+        }
+        Ok(Some(_)) => Check::ok(
+            "config",
+            format!(
+                "base URL {}, timeout {}s{}",
+                base_url,
+                config.timeout.as_secs(),
+                if config.dry_run { ", dry run" } else { "" }
+            ),
+        ),
+        Ok(None) => Check::failed("config", "no API key configured")
+            .with_hint("set SEE_API_KEY, or api_key in the config profile"),
+        Err(e) => Check::failed("config", format!("credential provider failed: {}", e))
+            .with_hint("check the command, file or variable the API key is read from"),
+    };
+
+    (diagnosis.push(check) != CheckStatus::Failed).then_some(base_url)
+}
+
+fn check_dns(client: &Client, base_url: &Url) -> Check {
+    let host = base_url.host_str().unwrap_or_default();
+    let port = base_url.port_or_known_default().unwrap_or(443);
+
+    let started = Instant::now();
+    match (host, port).to_socket_addrs() {
+        Ok(addrs) => {
+            let addrs: Vec<String> = addrs.map(|addr| addr.ip().to_string()).collect();
+            Check::ok(
+                "dns",
+                format!(
+                    "{} resolves to {} in {}ms",
+                    host,
+                    addrs.join(", "),
+                    started.elapsed().as_millis()
+                ),
+            )
+        }
+        // Behind a proxy only the proxy has to resolve the host
+        Err(e) if client.config().http.proxy.is_some() => {
+            Check::warning("dns", format!("{} does not resolve locally: {}", host, e))
+                .with_hint("fine if the proxy resolves it, otherwise check the host name")
+        }
+        Err(e) => Check::failed("dns", format!("{} does not resolve: {}", host, e))
+            .with_hint("check the host in base_url and the DNS settings of this machine"),
+    }
+}
+
+fn check_tls(client: &Client, base_url: &Url) -> Check {
+    if base_url.scheme() != "https" {
+        return Check::skipped("tls", "base URL uses plain HTTP");
+    }
+
+    let result = client
+        .http_client()
+        .head(base_url.as_str())
+        .timeout(client.config().timeout)
+        .send();
+
+    match result {
+        Ok(_) => Check::ok(
+            "tls",
+            format!("connected to {}", base_url.origin().ascii_serialization()),
+        ),
+        Err(e) => {
+            let error = Error::from(e);
+            Check::failed("tls", format!("cannot connect: {}", error))
+                .with_hint(request_hint(&error))
+        }
+    }
+}
+
+fn check_auth(response: Response) -> Check {
+    let status = response.status();
+    let body = response.text().unwrap_or_default();
+
+    match status {
+        StatusCode::OK => Check::ok("auth", format!("GET {} accepted the API key", PROBE_PATH)),
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+            Check::failed("auth", format!("the API key was rejected ({})", status))
+                .with_hint("check that the key is current and belongs to this account")
+        }
+        StatusCode::TOO_MANY_REQUESTS => Check::warning("auth", "rate limited")
+            .with_hint("wait for the limit to reset, or lower rate_limit and concurrency"),
+        StatusCode::NOT_FOUND => Check::failed("auth", format!("GET {} was not found", PROBE_PATH))
+            .with_hint("base_url should end in the API version, such as /api/v1"),
+        status => Check::failed(
+            "auth",
+            format!(
+                "unexpected {}: {}",
+                status,
+                body.chars().take(200).collect::<String>()
+            ),
+        )
+        .with_hint("the service may be having trouble, try again later"),
+    }
+}
+
+fn check_latency(client: &Client, latency: Duration) -> Check {
+    let detail = format!("authenticated call took {}ms", latency.as_millis());
+    let timeout = client.config().timeout;
+
+    if latency * 2 > timeout {
+        Check::warning("latency", detail)
+            .with_hint("this is close to the request timeout, raise timeout")
+    } else if latency > SLOW_LATENCY {
+        Check::warning("latency", detail).with_hint("check the network path or proxy")
+    } else {
+        Check::ok("latency", detail)
+    }
+}
+
+/// First header among `names` holding a number
+fn header_number(headers: &HeaderMap, names: &[&str]) -> Option<u64> {
+    names
+        .iter()
+        .find_map(|name| headers.get(*name)?.to_str().ok()?.trim().parse().ok())
+}
+
+fn check_rate_limit(headers: &HeaderMap) -> Check {
+    let remaining = header_number(headers, &["x-ratelimit-remaining", "ratelimit-remaining"]);
+    let limit = header_number(headers, &["x-ratelimit-limit", "ratelimit-limit"]);
+
+    match (remaining, limit) {
+        (None, _) => Check::skipped("rate_limit", "the service sent no rate limit headers"),
+        (Some(0), _) => Check::warning("rate_limit", "no requests left in this window")
+            .with_hint("wait for the window to reset, or lower rate_limit and concurrency"),
+        (Some(remaining), Some(limit)) if remaining * 100 < limit * LOW_RATE_LIMIT_PERCENT => {
+            Check::warning(
+                "rate_limit",
+                format!("{} of {} requests left", remaining, limit),
+            )
+            .with_hint("lower rate_limit and concurrency to stay under the limit")
+        }
+        (Some(remaining), Some(limit)) => Check::ok(
+            "rate_limit",
+            format!("{} of {} requests left", remaining, limit),
+        ),
+        (Some(remaining), None) => Check::ok("rate_limit", format!("{} requests left", remaining)),
+    }
+}
+
+fn check_clock(headers: &HeaderMap, now: SystemTime) -> Check {
+    let Some(server_time) = headers
+        .get(reqwest::header::DATE)
+        .and_then(|date| date.to_str().ok())
+        .and_then(|date| httpdate::parse_http_date(date).ok())
+    else {
+        return Check::skipped("clock", "the service sent no Date header");
+    };
+
+    let (skew, direction) = match now.duration_since(server_time) {
+        Ok(ahead) => (ahead, "ahead of"),
+        Err(behind) => (behind.duration(), "behind"),
+    };
+
+    // The Date header only has second precision
+    if skew > MAX_CLOCK_SKEW {
+        Check::warning(
+            "clock",
+            format!(
+                "local clock is {}s {} the service",
+                skew.as_secs(),
+                direction
+            ),
+        )
+        .with_hint("sync the clock with NTP, expire_at timestamps are computed locally")
+    } else {
+        Check::ok(
+            "clock",
+            format!("within {}s of the service", skew.as_secs().max(1)),
+        )
+    }
+}
+
+/// What to try when a request could not be sent
+fn request_hint(error: &Error) -> String {
+    let message = match error {
+        Error::HttpError(e) => {
+            let mut message = e.to_string();
+            let mut source = std::error::Error::source(e);
+            while let Some(inner) = source {
+                message.push_str(&format!(": {}", inner));
+                source = inner.source();
+            }
+            message.to_lowercase()
+        }
+        Error::CredentialError(_) => {
+            return "check the command, file or variable the API key is read from".to_string();
+        }
+        other => other.to_string().to_lowercase(),
+    };
+
+    if message.contains("certificate") || message.contains("tls") || message.contains("ssl") {
+        "the TLS handshake failed; behind an intercepting proxy, add its CA to root_certificates"
+            .to_string()
+    } else if message.contains("proxy") {
+        "check the proxy address and credentials, and no_proxy".to_string()
+    } else if message.contains("timed out") {
+        "the service did not answer in time; check firewalls and proxies, or raise timeout"
+            .to_string()
+    } else {
+        "check network access to the base URL, and the proxy settings".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::test_helpers::helpers::serve;
+    use reqwest::header::HeaderValue;
+
+    fn status_of(diagnosis: &Diagnosis, name: &str) -> CheckStatus {
+        diagnosis
+            .checks
+            .iter()
+            .find(|check| check.name == name)
+            .map(|check| check.status)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_diagnose_healthy_service() {
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nDate: {}\r\nX-RateLimit-Limit: 100\r\nX-RateLimit-Remaining: 5\r\nContent-Type: application/json\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{{}}",
+            httpdate::fmt_http_date(SystemTime::now())
+        );
+        let (base_url, server) = serve(vec![response]);
+
+        let client = Client::new(Config::new(base_url).with_api_key("test-key")).unwrap();
+        let diagnosis = diagnose(&client);
+
+        assert!(diagnosis.is_healthy(), "{}", diagnosis);
+        assert_eq!(status_of(&diagnosis, "config"), CheckStatus::Ok);
+        assert_eq!(status_of(&diagnosis, "dns"), CheckStatus::Ok);
+        assert_eq!(status_of(&diagnosis, "tls"), CheckStatus::Skipped);
+        assert_eq!(status_of(&diagnosis, "auth"), CheckStatus::Ok);
+        assert_eq!(status_of(&diagnosis, "rate_limit"), CheckStatus::Warning);
+        assert_eq!(status_of(&diagnosis, "clock"), CheckStatus::Ok);
+        assert_eq!(server.join().unwrap(), ["test-key"]);
+    }
+
+    #[test]
+    fn test_diagnose_rejected_key() {
+        let (base_url, server) = serve(vec![
+            "HTTP/1.1 401 Unauthorized\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        ]);
+
+        let client = Client::new(Config::new(base_url).with_api_key("bad-key")).unwrap();
+        let diagnosis = diagnose(&client);
+
+        assert!(!diagnosis.is_healthy());
+        let failure = diagnosis.failures().next().unwrap();
+        assert_eq!(failure.name, "auth");
+        assert!(failure.hint.is_some());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_diagnose_without_key_sends_nothing() {
+        let client = Client::new(Config::new("https://api.invalid")).unwrap();
+        let diagnosis = diagnose(&client);
+
+        assert_eq!(status_of(&diagnosis, "config"), CheckStatus::Failed);
+        assert!(
+            diagnosis.checks[1..]
+                .iter()
+                .all(|check| check.status == CheckStatus::Skipped)
+        );
+        assert!(!diagnosis.to_string().is_empty());
+    }
+
+    #[test]
+    fn test_config_failure() {
+        let diagnosis = config_failure(&Error::ConfigError(
+            "SEE_TIMEOUT=\"soon\": invalid digit found in string".to_string(),
+        ));
+
+        assert!(!diagnosis.is_healthy());
+        let failure = diagnosis.failures().next().unwrap();
+        assert_eq!(failure.name, "config");
+        assert!(failure.hint.as_deref().unwrap().contains("SEE_*"));
+        assert_eq!(diagnosis.checks.len(), 1 + AFTER_CONFIG.len());
+        assert!(
+            diagnosis.checks[1..]
+                .iter()
+                .all(|check| check.status == CheckStatus::Skipped)
+        );
+    }
+
+    #[test]
+    fn test_clock_skew() {
+        let now = SystemTime::now();
+        let mut headers = HeaderMap::new();
+        let server_time = now - Duration::from_secs(300);
+        headers.insert(
+            reqwest::header::DATE,
+            HeaderValue::from_str(&httpdate::fmt_http_date(server_time)).unwrap(),
+        );
+
+        let check = check_clock(&headers, now);
+        assert_eq!(check.status, CheckStatus::Warning);
+        assert!(check.detail.contains("ahead of"));
+
+        assert_eq!(
+            check_clock(&HeaderMap::new(), now).status,
+            CheckStatus::Skipped
+        );
+    }
+}
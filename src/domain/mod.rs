@@ -9,13 +9,14 @@
  * File Created: 2025-10-23 22:13:53
  *
  * Modified By: S.EE Development Team <dev@s.ee>
- * Last Modified: 2026-02-03 10:27:14
+ * Last Modified: 2026-10-19 11:06:12
  */
 
 use std::str;
 
 use crate::client::Client;
 use crate::error::Result;
+use crate::types::DomainName;
 use serde::{Deserialize, Serialize};
 
 /// Service for managing domains
@@ -24,13 +25,11 @@ pub trait DomainService {
     fn list(&self) -> Result<DomainListResponse>;
 }
 
-type Domain = String;
-
 /// Data structure for domain list
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct DomainListData {
     /// List of available domains
-    pub domains: Vec<Domain>,
+    pub domains: Vec<DomainName>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -69,7 +68,7 @@ mod tests {
 
         // Verify each domain is a valid non-empty string
         for domain in &response.data.domains {
-            assert!(!domain.is_empty(), "Domain should not be empty");
+            assert!(!domain.as_str().is_empty(), "Domain should not be empty");
         }
 
         Ok(())
@@ -9,7 +9,7 @@
  * File Created: 2025-10-23 11:21:39
  *
  * Modified By: S.EE Development Team <dev@s.ee>
- * Last Modified: 2026-02-03 10:28:06
+ * Last Modified: 2026-10-20 06:26:44
  */
 
 use serde::{Deserialize, Serialize};
@@ -34,6 +34,18 @@ pub enum Error {
     #[error("Invalid URL format: {0}")]
     InvalidUrl(String),
 
+    /// Invalid slug provided
+    #[error("Invalid slug: {0}")]
+    InvalidSlug(String),
+
+    /// Invalid domain name provided
+    #[error("Invalid domain name: {0}")]
+    InvalidDomain(String),
+
+    /// Invalid file key provided
+    #[error("Invalid file key: {0}")]
+    InvalidFileKey(String),
+
     /// URL parsing error
     #[error("URL parsing error: {0}")]
     UrlParseError(#[from] url::ParseError),
@@ -58,11 +70,34 @@ pub enum Error {
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 
+    /// Invalid configuration, from the environment or a config file
+    #[error("Configuration error: {0}")]
+    ConfigError(String),
+
+    /// The credential provider could not supply an API key
+    #[error("Credential error: {0}")]
+    CredentialError(String),
+
     /// Generic error
     #[error("Error: {0}")]
     GenericError(String),
 }
 
+impl Error {
+    /// Whether the server rejected the request because the resource already
+    /// exists, such as a custom slug that is taken
+    pub fn is_conflict(&self) -> bool {
+        match self {
+            Error::ServerError { status: 409, .. } => true,
+            Error::ServerError { message, .. } => {
+                let message = message.to_lowercase();
+                message.contains("already exists") || message.contains("taken")
+            }
+            _ => false,
+        }
+    }
+}
+
 impl From<Box<dyn std::error::Error>> for Error {
     fn from(err: Box<dyn std::error::Error>) -> Self {
         Error::GenericError(err.to_string())
@@ -71,3 +106,39 @@ impl From<Box<dyn std::error::Error>> for Error {
 
 /// Result type alias for content sharing operations
 pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_conflict() {
+        let conflict = Error::ServerError {
+            status: 409,
+            message: String::new(),
+        };
+        let taken = Error::ServerError {
+            status: 400,
+            message: "Slug already exists".to_string(),
+        };
+        let other = Error::ServerError {
+            status: 400,
+            message: "Invalid target URL".to_string(),
+        };
+        let missing = Error::ServerError {
+            status: 400,
+            message: "Slug does not exist".to_string(),
+        };
+        let missing_domain = Error::ServerError {
+            status: 400,
+            message: "Domain does not exist".to_string(),
+        };
+
+        assert!(conflict.is_conflict());
+        assert!(taken.is_conflict());
+        assert!(!other.is_conflict());
+        assert!(!missing.is_conflict());
+        assert!(!missing_domain.is_conflict());
+        assert!(!Error::RateLimited.is_conflict());
+    }
+}
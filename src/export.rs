@@ -0,0 +1,341 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: export.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-20 03:06:40
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 07:48:10
+ */
+
+//! Export of the links, texts and files recorded in the
+//! [ledger](crate::ledger), with visit counts, to CSV or JSON lines.
+//!
+//! Export runs in up to three steps: [`collect`] reads the live resources
+//! from the ledger, [`fetch_stats`] adds the visit count of every link, and
+//! [`write()`] prints the records in the chosen format.
+
+use crate::batch::{BatchOptions, run_batch};
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::ledger::{CreatedResource, Ledger, LedgerEntry, LedgerQuery, ResourceKind};
+use crate::types::{DomainName, FileKey, Slug, TagId};
+use crate::url::ShortenService;
+use crate::url::models::GetLinkVisitStatRequest;
+use serde::Serialize;
+use std::io::Write;
+use std::path::Path;
+
+/// Format of an export file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// One row per resource, tags separated by `;`
+    Csv,
+
+    /// One JSON object per line
+    JsonLines,
+}
+
+impl ExportFormat {
+    /// Guess the format from the file extension
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "csv" => Some(Self::Csv),
+            "jsonl" | "ndjson" => Some(Self::JsonLines),
+            _ => None,
+        }
+    }
+}
+
+/// A resource as exported
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExportRecord {
+    pub kind: ResourceKind,
+
+    /// Domain of a link or text
+    pub domain: Option<DomainName>,
+
+    /// Slug of a link or text
+    pub slug: Option<Slug>,
+
+    /// Key of a file
+    pub key: Option<FileKey>,
+
+    /// The full short URL, or file URL
+    pub short_url: Option<String>,
+
+    /// The target URL of a link
+    pub target_url: Option<String>,
+
+    /// The title of a link or text, or the file name of a file
+    pub title: Option<String>,
+
+    pub tag_ids: Vec<TagId>,
+
+    /// Expiration timestamp (Unix epoch)
+    pub expire_at: Option<i64>,
+
+    /// When the resource was created (Unix epoch seconds)
+    pub created_at: i64,
+
+    /// Visits of a link, set by [`fetch_stats`]
+    pub visit_count: Option<i64>,
+
+    /// Why the visit count could not be fetched
+    pub error: Option<String>,
+}
+
+impl From<LedgerEntry> for ExportRecord {
+    fn from(entry: LedgerEntry) -> Self {
+        let kind = entry.resource.kind();
+        let (domain, slug, key) = match entry.resource {
+            CreatedResource::Link { domain, slug } | CreatedResource::Text { domain, slug } => {
+                (Some(domain), Some(slug), None)
+            }
+            CreatedResource::File { key } => (None, None, Some(key)),
+        };
+
+        Self {
+            kind,
+            domain,
+            slug,
+            key,
+            short_url: entry.short_url,
+            target_url: entry.target_url,
+            title: entry.title,
+            tag_ids: entry.tag_ids,
+            expire_at: entry.expire_at,
+            created_at: entry.timestamp,
+            visit_count: None,
+            error: None,
+        }
+    }
+}
+
+/// One line of a CSV export, with the tags joined into a single column
+#[derive(Serialize)]
+struct CsvRecord<'a> {
+    kind: ResourceKind,
+    domain: Option<&'a DomainName>,
+    slug: Option<&'a Slug>,
+    key: Option<&'a FileKey>,
+    short_url: Option<&'a str>,
+    target_url: Option<&'a str>,
+    title: Option<&'a str>,
+    tag_ids: String,
+    expire_at: Option<i64>,
+    created_at: i64,
+    visit_count: Option<i64>,
+    error: Option<&'a str>,
+}
+
+impl<'a> From<&'a ExportRecord> for CsvRecord<'a> {
+    fn from(record: &'a ExportRecord) -> Self {
+        Self {
+            kind: record.kind,
+            domain: record.domain.as_ref(),
+            slug: record.slug.as_ref(),
+            key: record.key.as_ref(),
+            short_url: record.short_url.as_deref(),
+            target_url: record.target_url.as_deref(),
+            title: record.title.as_deref(),
+            tag_ids: record
+                .tag_ids
+                .iter()
+                .map(TagId::to_string)
+                .collect::<Vec<_>>()
+                .join(";"),
+            expire_at: record.expire_at,
+            created_at: record.created_at,
+            visit_count: record.visit_count,
+            error: record.error.as_deref(),
+        }
+    }
+}
+
+/// Resources in the ledger that were created and not deleted since,
+/// filtered by `query`
+pub fn collect(ledger: &Ledger, query: &LedgerQuery) -> Result<Vec<ExportRecord>> {
+    Ok(ledger
+        .live()?
+        .into_iter()
+        .filter(|entry| query.matches(entry))
+        .map(ExportRecord::from)
+        .collect())
+}
+
+/// Set the visit count of every link, over `period` if given
+///
+/// A failed lookup is kept in [`ExportRecord::error`] instead of failing the
+/// whole export; texts and files are left as they are.
+pub fn fetch_stats(
+    client: &Client,
+    records: Vec<ExportRecord>,
+    period: Option<&str>,
+    options: &BatchOptions,
+) -> Vec<ExportRecord> {
+    run_batch(records, options, |mut record| {
+        if let (ResourceKind::Link, Some(domain), Some(slug)) =
+            (record.kind, &record.domain, &record.slug)
+        {
+            match client.get_link_visit_stat(GetLinkVisitStatRequest {
+                domain: domain.clone(),
+                slug: slug.clone(),
+                period: period.map(str::to_string),
+            }) {
+                Ok(response) => record.visit_count = Some(response.data.visit_count),
+                Err(e) => record.error = Some(e.to_string()),
+            }
+        }
+        Ok(record)
+    })
+    .results
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Write the records in the given format
+pub fn write<W: Write>(
+    records: &[ExportRecord],
+    format: ExportFormat,
+    mut writer: W,
+) -> Result<()> {
+    match format {
+        ExportFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(writer);
+            for record in records {
+                writer
+                    .serialize(CsvRecord::from(record))
+                    .map_err(|e| Error::GenericError(format!("cannot write CSV: {}", e)))?;
+            }
+            writer.flush()?;
+        }
+        ExportFormat::JsonLines => {
+            for record in records {
+                serde_json::to_writer(&mut writer, record)?;
+                writer.write_all(b"\n")?;
+            }
+            writer.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::LedgerAction;
+    use std::env;
+    use std::fs;
+
+    fn ledger_with(name: &str, entries: &[LedgerEntry]) -> Ledger {
+        let mut path = env::temp_dir();
+        path.push(format!("see-sdk-{}-{}.jsonl", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let ledger = Ledger::open(path).unwrap();
+        for entry in entries {
+            ledger.append(entry).unwrap();
+        }
+        ledger
+    }
+
+    fn link(slug: &str) -> CreatedResource {
+        CreatedResource::Link {
+            domain: DomainName::default(),
+            slug: Slug::new(slug).unwrap(),
+        }
+    }
+
+    fn sample_ledger(name: &str) -> Ledger {
+        ledger_with(
+            name,
+            &[
+                LedgerEntry {
+                    timestamp: 1_000,
+                    short_url: Some("https://s.ee/spring".to_string()),
+                    target_url: Some("https://example.com/".to_string()),
+                    title: Some("Spring, \"sale\"".to_string()),
+                    tag_ids: vec![TagId::new(1), TagId::new(2)],
+                    ..LedgerEntry::new(LedgerAction::Create, link("spring"))
+                },
+                LedgerEntry::new(LedgerAction::Create, link("gone")),
+                LedgerEntry::new(LedgerAction::Delete, link("gone")),
+                LedgerEntry {
+                    timestamp: 2_000,
+                    ..LedgerEntry::new(
+                        LedgerAction::Create,
+                        CreatedResource::File {
+                            key: FileKey::new("abc123").unwrap(),
+                        },
+                    )
+                },
+            ],
+        )
+    }
+
+    #[test]
+    fn test_collect_live_resources() -> Result<()> {
+        let ledger = sample_ledger("export-collect");
+
+        let records = collect(&ledger, &LedgerQuery::new())?;
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].slug.as_ref().unwrap(), "spring");
+        assert_eq!(records[0].created_at, 1_000);
+        assert_eq!(records[1].key.as_ref().unwrap(), "abc123");
+
+        let links = collect(&ledger, &LedgerQuery::new().with_kind(ResourceKind::Link))?;
+        assert_eq!(links.len(), 1);
+
+        fs::remove_file(ledger.path())?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_csv_and_json_lines() -> Result<()> {
+        let ledger = sample_ledger("export-write");
+        let mut records = collect(&ledger, &LedgerQuery::new())?;
+        records[0].visit_count = Some(42);
+
+        let mut csv = Vec::new();
+        write(&records, ExportFormat::Csv, &mut csv)?;
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "kind,domain,slug,key,short_url,target_url,title,tag_ids,expire_at,created_at,visit_count,error"
+        );
+        assert_eq!(
+            lines[1],
+            "link,s.ee,spring,,https://s.ee/spring,https://example.com/,\"Spring, \"\"sale\"\"\",1;2,,1000,42,"
+        );
+        assert!(lines[2].starts_with("file,,,abc123,"));
+
+        let mut jsonl = Vec::new();
+        write(&records, ExportFormat::JsonLines, &mut jsonl)?;
+        let first: serde_json::Value =
+            serde_json::from_str(String::from_utf8(jsonl).unwrap().lines().next().unwrap())?;
+        assert_eq!(first["tag_ids"], serde_json::json!([1, 2]));
+        assert_eq!(first["visit_count"], 42);
+
+        fs::remove_file(ledger.path())?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(ExportFormat::from_path("out.csv"), Some(ExportFormat::Csv));
+        assert_eq!(
+            ExportFormat::from_path("out.jsonl"),
+            Some(ExportFormat::JsonLines)
+        );
+        assert_eq!(ExportFormat::from_path("out.parquet"), None);
+    }
+}
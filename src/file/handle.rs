@@ -0,0 +1,60 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: handle.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-19 09:30:18
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-19 11:06:12
+ */
+
+use crate::client::Client;
+use crate::error::Result;
+use crate::file::FileService;
+use crate::file::models::{FileData, FileDeleteResponse};
+use crate::types::FileKey;
+
+/// An uploaded file bound to the client that manages it
+///
+/// Returned by [`FileService::upload_file_handle`].
+#[derive(Debug, Clone)]
+pub struct FileHandle {
+    client: Client,
+    data: FileData,
+}
+
+impl FileHandle {
+    /// Attach a handle to the data of an uploaded file
+    pub fn new(client: Client, data: FileData) -> Self {
+        Self { client, data }
+    }
+
+    /// The full URL of the file
+    pub fn url(&self) -> &str {
+        &self.data.url
+    }
+
+    /// The file hash, used as its key
+    pub fn hash(&self) -> &FileKey {
+        &self.data.hash
+    }
+
+    /// All data returned for the upload
+    pub fn data(&self) -> &FileData {
+        &self.data
+    }
+
+    /// Consume the handle, returning the upload data
+    pub fn into_data(self) -> FileData {
+        self.data
+    }
+
+    /// Delete the file, consuming the handle
+    pub fn delete(self) -> Result<FileDeleteResponse> {
+        self.client.delete_file(&self.data.hash)
+    }
+}
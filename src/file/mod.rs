@@ -9,23 +9,31 @@
  * File Created: 2026-01-19 23:32:47
  *
  * Modified By: S.EE Development Team <dev@s.ee>
- * Last Modified: 2026-01-20 10:55:11
+ * Last Modified: 2026-10-20 06:58:31
  */
 
 use crate::client::Client;
 use crate::error::{Error, Result};
+use crate::file::handle::FileHandle;
 use crate::file::models::{FileDeleteResponse, FileDomainsResponse, FileUploadResponse};
+use crate::ledger::{CreatedResource, LedgerAction, LedgerEntry};
+use crate::telemetry;
+use crate::types::FileKey;
 use reqwest::blocking::multipart::Form;
 use std::path::Path;
 
+pub mod handle;
 pub mod models;
 
 pub trait FileService {
     /// Upload a file for sharing
     fn upload_file<P: AsRef<Path>>(&self, file_path: P) -> Result<FileUploadResponse>;
 
+    /// Upload a file and return a handle bound to it
+    fn upload_file_handle<P: AsRef<Path>>(&self, file_path: P) -> Result<FileHandle>;
+
     /// Delete a shared file
-    fn delete_file(&self, key: &str) -> Result<FileDeleteResponse>;
+    fn delete_file(&self, key: &FileKey) -> Result<FileDeleteResponse>;
 
     /// Get available domains for file sharing
     fn get_file_domains(&self) -> Result<FileDomainsResponse>;
@@ -36,15 +44,78 @@ impl FileService for Client {
     fn upload_file<P: AsRef<Path>>(&self, file_path: P) -> Result<FileUploadResponse> {
         let path = file_path.as_ref();
 
+        if self.is_dry_run() {
+            let metadata = std::fs::metadata(path)?;
+            if !metadata.is_file() {
+                return Err(Error::GenericError(format!(
+                    "Not a file: {}",
+                    path.display()
+                )));
+            }
+
+            let filename = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+
+            log::info!(
+                "dry run: {} {} multipart file={} ({} bytes)",
+                reqwest::Method::POST,
+                self.build_api_url("/file/upload"),
+                path.display(),
+                metadata.len()
+            );
+            return Ok(FileUploadResponse::dry_run(filename, metadata.len()));
+        }
+
         let form = Form::new().file("file", path).map_err(Error::IoError)?;
 
-        self.execute_multipart_request(reqwest::Method::POST, "/file/upload", form)
+        let response: FileUploadResponse =
+            self.execute_multipart_request(reqwest::Method::POST, "/file/upload", form)?;
+        telemetry::record_upload(response.data.size);
+
+        self.record(|| LedgerEntry {
+            short_url: Some(response.data.url.clone()),
+            title: Some(response.data.filename.clone()),
+            delete_url: Some(response.data.delete.clone()),
+            ..LedgerEntry::new(
+                LedgerAction::Create,
+                CreatedResource::File {
+                    key: response.data.hash.clone(),
+                },
+            )
+        });
+
+        Ok(response)
+    }
+
+    /// Upload a file and bind the result to this client
+    fn upload_file_handle<P: AsRef<Path>>(&self, file_path: P) -> Result<FileHandle> {
+        let response = self.upload_file(file_path)?;
+        Ok(FileHandle::new(self.clone(), response.data))
     }
 
     /// Delete a file by key
-    fn delete_file(&self, key: &str) -> Result<FileDeleteResponse> {
+    fn delete_file(&self, key: &FileKey) -> Result<FileDeleteResponse> {
         let path = format!("/file/delete/{}", key);
-        self.execute_request_no_body(reqwest::Method::GET, &path)
+
+        if self.is_dry_run() {
+            self.log_dry_run(reqwest::Method::GET, &path, &())?;
+            return Ok(FileDeleteResponse::dry_run());
+        }
+        let response: FileDeleteResponse =
+            self.execute_request_no_body(reqwest::Method::GET, &path)?;
+
+        if response.success {
+            self.record(|| {
+                LedgerEntry::new(
+                    LedgerAction::Delete,
+                    CreatedResource::File { key: key.clone() },
+                )
+            });
+        }
+
+        Ok(response)
     }
 
     /// Get available file domains
@@ -56,11 +127,33 @@ impl FileService for Client {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::Config;
     use crate::test_helpers::helpers::{create_test_client, get_api_key_or_skip};
     use std::env;
     use std::fs::{self, File};
     use std::io::Write;
 
+    #[test]
+    fn test_dry_run_upload() {
+        let config = Config::new("http://127.0.0.1:9")
+            .with_max_retries(0)
+            .with_dry_run(true);
+        let client = Client::new(config).unwrap();
+
+        let mut temp_path = env::temp_dir();
+        temp_path.push(format!("test_dry_run_upload_{}.txt", std::process::id()));
+        fs::write(&temp_path, "dry run").unwrap();
+
+        let response = client.upload_file(&temp_path).unwrap();
+        assert_eq!(response.data.size, 7);
+        assert!(response.data.url.ends_with(".txt"));
+        assert!(client.delete_file(&response.data.hash).unwrap().success);
+
+        // Missing files are still reported
+        let _ = fs::remove_file(&temp_path);
+        assert!(client.upload_file(&temp_path).is_err());
+    }
+
     #[test]
     fn test_file_upload_and_delete() {
         if get_api_key_or_skip().is_none() {
@@ -83,7 +176,7 @@ mod tests {
         // 1. Test Upload
         let upload_response = client.upload_file(&temp_path).unwrap();
         assert_eq!(upload_response.code, 200);
-        assert!(!upload_response.data.hash.is_empty());
+        assert!(!upload_response.data.hash.as_str().is_empty());
 
         let file_key = &upload_response.data.hash;
 
@@ -9,9 +9,10 @@
  * File Created: 2026-01-19 23:32:10
  *
  * Modified By: S.EE Development Team <dev@s.ee>
- * Last Modified: 2026-01-19 23:50:06
+ * Last Modified: 2026-10-20 07:15:36
  */
 
+use crate::types::{DRY_RUN_MESSAGE, DRY_RUN_SLUG, DomainName, FileKey};
 use serde::{Deserialize, Serialize};
 
 /// Data structure for file information
@@ -27,7 +28,7 @@ pub struct FileData {
     pub filename: String,
 
     /// File hash/key
-    pub hash: String,
+    pub hash: FileKey,
 
     /// Image height (if applicable)
     #[serde(default)]
@@ -65,6 +66,32 @@ pub struct FileUploadResponse {
     pub data: FileData,
 }
 
+impl FileUploadResponse {
+    /// Synthetic response for an upload that was not actually sent
+    pub(crate) fn dry_run(filename: String, size: u64) -> Self {
+        let key = FileKey::new(DRY_RUN_SLUG).expect("valid dry-run key");
+
+        Self {
+            code: reqwest::StatusCode::OK.as_u16().into(),
+            message: DRY_RUN_MESSAGE.to_string(),
+            data: FileData {
+                delete: String::new(),
+                file_id: 0,
+                url: format!("https://{}/{}/{}", DomainName::default(), key, filename),
+                storename: filename.clone(),
+                filename,
+                hash: key,
+                height: None,
+                page: None,
+                path: String::new(),
+                size,
+                upload_status: 0,
+                width: None,
+            },
+        }
+    }
+}
+
 /// Response structure for file deletion
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct FileDeleteResponse {
@@ -73,10 +100,21 @@ pub struct FileDeleteResponse {
     pub success: bool,
 }
 
+impl FileDeleteResponse {
+    /// Synthetic response for a deletion that was not actually sent
+    pub(crate) fn dry_run() -> Self {
+        Self {
+            code: reqwest::StatusCode::OK.as_u16().to_string(),
+            message: DRY_RUN_MESSAGE.to_string(),
+            success: true,
+        }
+    }
+}
+
 /// Data structure for domains
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct FileDomainsData {
-    pub domains: Vec<String>,
+    pub domains: Vec<DomainName>,
 }
 
 /// Response structure for file domains
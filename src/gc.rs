@@ -0,0 +1,412 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: gc.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-19 19:05:27
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 08:47:05
+ */
+
+//! Garbage collection of expired, outdated and temporary resources found in
+//! the [ledger](crate::ledger).
+//!
+//! Collection runs in two steps: [`plan`] lists what would be deleted and
+//! why, which doubles as the dry-run report, and [`sweep`] deletes it.
+
+use crate::batch::{BatchOptions, run_batch};
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::ledger::{Ledger, LedgerAction, LedgerEntry, ResourceKind, now};
+use crate::types::TagId;
+use serde::Serialize;
+use std::fmt;
+use std::time::Duration;
+
+/// Which resources are garbage
+#[derive(Debug, Clone)]
+pub struct GcPolicy {
+    /// Collect links and texts whose `expire_at` has passed
+    pub expired: bool,
+
+    /// Collect files uploaded longer ago than this
+    pub file_retention: Option<Duration>,
+
+    /// Collect resources carrying any of these tags
+    pub temporary_tags: Vec<TagId>,
+}
+
+impl Default for GcPolicy {
+    fn default() -> Self {
+        Self {
+            expired: true,
+            file_retention: None,
+            temporary_tags: Vec::new(),
+        }
+    }
+}
+
+impl GcPolicy {
+    /// Create a policy collecting expired links and texts
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether expired links and texts are collected
+    pub fn with_expired(mut self, expired: bool) -> Self {
+        self.expired = expired;
+        self
+    }
+
+    /// Collect files older than the retention period
+    pub fn with_file_retention(mut self, retention: Duration) -> Self {
+        self.file_retention = Some(retention);
+        self
+    }
+
+    /// Collect resources carrying the tag
+    pub fn with_temporary_tag(mut self, tag: TagId) -> Self {
+        self.temporary_tags.push(tag);
+        self
+    }
+
+    /// Why an entry is garbage under this policy at time `now`, if it is
+    fn reason(&self, entry: &LedgerEntry, now: i64) -> Option<GcReason> {
+        let kind = entry.resource.kind();
+
+        if self.expired
+            && kind != ResourceKind::File
+            && entry.expire_at.is_some_and(|expire_at| expire_at <= now)
+        {
+            return Some(GcReason::Expired);
+        }
+
+        if let Some(retention) = self.file_retention
+            && kind == ResourceKind::File
+            && entry.timestamp.saturating_add(retention.as_secs() as i64) <= now
+        {
+            return Some(GcReason::RetentionElapsed);
+        }
+
+        if entry
+            .tag_ids
+            .iter()
+            .any(|tag| self.temporary_tags.contains(tag))
+        {
+            return Some(GcReason::Temporary);
+        }
+
+        None
+    }
+}
+
+/// Why a resource is collected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GcReason {
+    /// The link or text is past its expiration time
+    Expired,
+
+    /// The file is older than the retention period
+    RetentionElapsed,
+
+    /// The resource carries a temporary tag
+    Temporary,
+}
+
+impl fmt::Display for GcReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            GcReason::Expired => "expired",
+            GcReason::RetentionElapsed => "retention elapsed",
+            GcReason::Temporary => "temporary",
+        })
+    }
+}
+
+/// A resource selected for deletion
+#[derive(Debug, Clone, Serialize)]
+pub struct GcCandidate {
+    pub entry: LedgerEntry,
+    pub reason: GcReason,
+}
+
+/// Resources that a sweep would delete
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GcPlan {
+    pub candidates: Vec<GcCandidate>,
+}
+
+impl GcPlan {
+    /// Whether there is nothing to collect
+    pub fn is_empty(&self) -> bool {
+        self.candidates.is_empty()
+    }
+
+    /// Number of resources to collect
+    pub fn len(&self) -> usize {
+        self.candidates.len()
+    }
+}
+
+/// Human-readable dry-run report, one line per resource
+impl fmt::Display for GcPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} resource(s) to delete", self.candidates.len())?;
+
+        for candidate in &self.candidates {
+            let entry = &candidate.entry;
+            writeln!(
+                f,
+                "  {:?} {} ({})",
+                entry.resource.kind(),
+                entry.short_url.as_deref().unwrap_or("-"),
+                candidate.reason
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Outcome of a sweep
+#[derive(Debug, Default)]
+pub struct GcReport {
+    /// Resources that were deleted, or were already gone
+    pub deleted: Vec<GcCandidate>,
+
+    /// Resources that could not be deleted, with the reason
+    pub failed: Vec<(GcCandidate, Error)>,
+}
+
+/// List the live resources in the ledger that are garbage under the policy
+pub fn plan(ledger: &Ledger, policy: &GcPolicy) -> Result<GcPlan> {
+    plan_at(ledger, policy, now())
+}
+
+/// Like [`plan`], evaluating the policy at the given time (Unix epoch)
+pub fn plan_at(ledger: &Ledger, policy: &GcPolicy, now: i64) -> Result<GcPlan> {
+    let candidates = ledger
+        .live()?
+        .into_iter()
+        .filter_map(|entry| {
+            policy
+                .reason(&entry, now)
+                .map(|reason| GcCandidate { entry, reason })
+        })
+        .collect();
+
+    Ok(GcPlan { candidates })
+}
+
+/// Delete everything in the plan, which was made from `ledger`
+///
+/// Resources the service no longer knows are counted as deleted. Every
+/// deletion is written to `ledger`, so swept resources aren't planned again
+/// even when it isn't the client's own ledger. A dry-run sweep deletes
+/// nothing, so it leaves `ledger` untouched.
+pub fn sweep(client: &Client, ledger: &Ledger, plan: GcPlan, options: &BatchOptions) -> GcReport {
+    // The client already records its successful deletions to its own ledger,
+    // and a dry-run client's deletions never happened
+    let skip_record = client.is_dry_run()
+        || client
+            .ledger()
+            .is_some_and(|own| own.path() == ledger.path());
+
+    let batch = run_batch(plan.candidates.clone(), options, |candidate| {
+        let resource = &candidate.entry.resource;
+        match resource.delete(client) {
+            Ok(()) if skip_record => return Ok(()),
+            Ok(()) | Err(Error::NotFound) | Err(Error::ServerError { status: 404, .. }) => {}
+            Err(e) => return Err(e),
+        }
+
+        let entry = LedgerEntry::new(LedgerAction::Delete, resource.clone());
+        if let Err(e) = ledger.append(&entry) {
+            log::warn!("failed to write ledger {}: {}", ledger.path().display(), e);
+        }
+        Ok(())
+    });
+
+    let mut report = GcReport::default();
+    for (candidate, result) in plan.candidates.into_iter().zip(batch.results) {
+        match result {
+            Ok(()) => report.deleted.push(candidate),
+            Err(e) => report.failed.push((candidate, e)),
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::ledger::CreatedResource;
+    use crate::test_helpers::helpers::serve;
+    use crate::types::{DomainName, FileKey, Slug};
+    use std::env;
+    use std::fs;
+
+    fn link(slug: &str, expire_at: Option<i64>, tags: &[u32]) -> LedgerEntry {
+        LedgerEntry {
+            timestamp: 1_000,
+            expire_at,
+            tag_ids: tags.iter().copied().map(TagId::new).collect(),
+            ..LedgerEntry::new(
+                LedgerAction::Create,
+                CreatedResource::Link {
+                    domain: DomainName::default(),
+                    slug: Slug::new(slug).unwrap(),
+                },
+            )
+        }
+    }
+
+    fn file(key: &str, timestamp: i64) -> LedgerEntry {
+        LedgerEntry {
+            timestamp,
+            ..LedgerEntry::new(
+                LedgerAction::Create,
+                CreatedResource::File {
+                    key: FileKey::new(key).unwrap(),
+                },
+            )
+        }
+    }
+
+    fn ledger_with(name: &str, entries: &[LedgerEntry]) -> Ledger {
+        let mut path = env::temp_dir();
+        path.push(format!("see-sdk-{}-{}.jsonl", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let ledger = Ledger::open(path).unwrap();
+        for entry in entries {
+            ledger.append(entry).unwrap();
+        }
+        ledger
+    }
+
+    #[test]
+    fn test_plan_selects_garbage() -> Result<()> {
+        let ledger = ledger_with(
+            "gc-plan",
+            &[
+                link("expired", Some(1_500), &[]),
+                link("current", Some(5_000), &[]),
+                link("temp", None, &[9]),
+                file("old", 1_000),
+                file("new", 1_900),
+            ],
+        );
+
+        let policy = GcPolicy::new()
+            .with_file_retention(Duration::from_secs(500))
+            .with_temporary_tag(TagId::new(9));
+        let plan = plan_at(&ledger, &policy, 2_000)?;
+
+        let reasons: Vec<GcReason> = plan.candidates.iter().map(|c| c.reason).collect();
+        assert_eq!(
+            reasons,
+            vec![
+                GcReason::Expired,
+                GcReason::Temporary,
+                GcReason::RetentionElapsed
+            ]
+        );
+        assert!(plan.to_string().starts_with("3 resource(s) to delete"));
+
+        let plan = plan_at(&ledger, &GcPolicy::new().with_expired(false), 2_000)?;
+        assert!(plan.is_empty());
+
+        let _ = fs::remove_file(ledger.path());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sweep_reports_failures() -> Result<()> {
+        let ledger = ledger_with("gc-sweep", &[file("old", 0)]);
+        let policy = GcPolicy::new().with_file_retention(Duration::from_secs(1));
+        let plan = plan(&ledger, &policy)?;
+        assert_eq!(plan.len(), 1);
+
+        let client = Client::new(Config::new("http://127.0.0.1:9").with_max_retries(0))?;
+        let report = sweep(&client, &ledger, plan, &BatchOptions::default());
+        assert!(report.deleted.is_empty());
+        assert_eq!(report.failed.len(), 1);
+
+        let _ = fs::remove_file(ledger.path());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sweep_records_to_planned_ledger() -> Result<()> {
+        let ledger = ledger_with(
+            "gc-sweep-ledger",
+            &[
+                link("deleted", Some(1_500), &[]),
+                link("gone", Some(1_500), &[]),
+                file("old", 0),
+            ],
+        );
+        let policy = GcPolicy::new().with_file_retention(Duration::from_secs(1));
+        let plan = plan(&ledger, &policy)?;
+        assert_eq!(plan.len(), 3);
+
+        let response = |body: &str| {
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+        };
+        let (base_url, server) = serve(vec![
+            response(r#"{"code":200,"message":"success","data":null}"#),
+            "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string(),
+            response(r#"{"code":"error","message":"File already deleted","success":false}"#),
+        ]);
+
+        // The client has no ledger of its own
+        let client = Client::new(Config::new(base_url).with_api_key("key"))?;
+        let options = BatchOptions::default().with_concurrency(1);
+        let report = sweep(&client, &ledger, plan, &options);
+        server.join().unwrap();
+
+        assert_eq!(report.deleted.len(), 3);
+        assert!(report.failed.is_empty());
+        assert!(super::plan(&ledger, &policy)?.is_empty());
+
+        let _ = fs::remove_file(ledger.path());
+        Ok(())
+    }
+
+    #[test]
+    fn test_dry_run_sweep_leaves_ledger_unchanged() -> Result<()> {
+        let ledger = ledger_with(
+            "gc-sweep-dry-run",
+            &[link("expired", Some(1_500), &[]), file("old", 0)],
+        );
+        let policy = GcPolicy::new().with_file_retention(Duration::from_secs(1));
+        let plan = plan(&ledger, &policy)?;
+        assert_eq!(plan.len(), 2);
+        let live = ledger.live()?.len();
+
+        let config = Config::new("http://127.0.0.1:9")
+            .with_max_retries(0)
+            .with_dry_run(true);
+        let client = Client::new(config)?;
+        let report = sweep(&client, &ledger, plan, &BatchOptions::default());
+
+        assert_eq!(report.deleted.len(), 2);
+        assert_eq!(ledger.live()?.len(), live);
+        assert_eq!(super::plan(&ledger, &policy)?.len(), 2);
+
+        let _ = fs::remove_file(ledger.path());
+        Ok(())
+    }
+}
@@ -0,0 +1,555 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: import.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-20 02:40:12
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 08:59:02
+ */
+
+//! Bulk import of links from CSV or JSON files.
+//!
+//! Columns are matched by name, ignoring case, and mapped onto
+//! [`ShortenRequest`] fields:
+//!
+//! | Column | Field |
+//! |--------|-------|
+//! | `url`, `target_url` | `target_url` (required) |
+//! | `slug`, `custom_slug` | `custom_slug` |
+//! | `title` | `title` |
+//! | `domain` | `domain`, the default domain when empty |
+//! | `tags`, `tag_ids` | `tag_ids`, separated by `;`, `,` or spaces |
+//! | `expire_at`, `expires` | `expire_at`, Unix epoch seconds, RFC 3339 or `YYYY-MM-DD` (midnight UTC) |
+//! | `expiration_redirect_url` | `expiration_redirect_url` |
+//! | `password` | `password` |
+//!
+//! Other columns are ignored. A JSON file holds an array of objects with the
+//! same keys.
+//!
+//! Import runs in two steps: [`plan`] reads and validates every row, so a
+//! bad file is rejected before anything is created, and [`run`] shortens the
+//! rows through the [batch](crate::batch) pool.
+
+use crate::batch::{BatchOptions, BatchSummary, run_batch};
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::types::{DomainName, Secret, Slug, TagId};
+use crate::url::ShortenService;
+use crate::url::models::{ShortenRequest, ShortenResponse};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::io::{Read, Write};
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+use url::Url;
+
+/// Format of an import file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    Csv,
+    Json,
+}
+
+impl ImportFormat {
+    /// Guess the format from the file extension
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "csv" => Some(Self::Csv),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+/// A validated row, ready to be shortened
+#[derive(Debug, Clone)]
+pub struct ImportRow {
+    /// Row number in the file, starting at 1 for the first data row
+    pub row: usize,
+
+    pub request: ShortenRequest,
+}
+
+/// Why a row was rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowError {
+    /// Row number in the file, starting at 1 for the first data row
+    pub row: usize,
+
+    pub message: String,
+}
+
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "row {}: {}", self.row, self.message)
+    }
+}
+
+/// Rows read from an import file
+#[derive(Debug, Clone, Default)]
+pub struct ImportPlan {
+    pub rows: Vec<ImportRow>,
+
+    /// Rows that failed validation; [`run`] refuses a plan with errors
+    pub errors: Vec<RowError>,
+}
+
+impl ImportPlan {
+    /// Whether every row passed validation
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Whether there is nothing to import
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Number of valid rows
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+/// Result of shortening one row
+#[derive(Debug)]
+pub struct ImportOutcome {
+    pub row: ImportRow,
+    pub result: Result<ShortenResponse>,
+}
+
+/// Results of an import, in file order
+#[derive(Debug)]
+pub struct ImportReport {
+    pub outcomes: Vec<ImportOutcome>,
+    pub summary: BatchSummary,
+}
+
+/// One line of the CSV written by [`ImportReport::write_csv`]
+#[derive(Serialize)]
+struct ReportRecord<'a> {
+    row: usize,
+    target_url: &'a str,
+    slug: &'a str,
+    short_url: &'a str,
+    error: String,
+}
+
+impl ImportReport {
+    /// Whether every row was shortened
+    pub fn is_success(&self) -> bool {
+        self.summary.failed == 0
+    }
+
+    /// Write one CSV line per row with the short URL or the error
+    ///
+    /// The columns are `row`, `target_url`, `slug`, `short_url` and `error`.
+    pub fn write_csv<W: Write>(&self, writer: W) -> Result<()> {
+        let mut writer = csv::Writer::from_writer(writer);
+
+        for outcome in &self.outcomes {
+            let (slug, short_url, error) = match &outcome.result {
+                Ok(response) => (
+                    response.data.slug.as_ref(),
+                    response.data.short_url.as_str(),
+                    String::new(),
+                ),
+                Err(e) => (
+                    outcome
+                        .row
+                        .request
+                        .custom_slug
+                        .as_ref()
+                        .map_or("", |slug| slug.as_ref()),
+                    "",
+                    e.to_string(),
+                ),
+            };
+
+            writer
+                .serialize(ReportRecord {
+                    row: outcome.row.row,
+                    target_url: &outcome.row.request.target_url,
+                    slug,
+                    short_url,
+                    error,
+                })
+                .map_err(csv_error)?;
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Read and validate every row of an import file
+///
+/// Rows without a domain use `default_domain`. Only unreadable files are
+/// errors; invalid rows are collected in [`ImportPlan::errors`].
+pub fn plan<R: Read>(
+    reader: R,
+    format: ImportFormat,
+    default_domain: &DomainName,
+) -> Result<ImportPlan> {
+    let records = match format {
+        ImportFormat::Csv => read_csv(reader)?,
+        ImportFormat::Json => read_json(reader)?,
+    };
+
+    let mut plan = ImportPlan::default();
+    let mut slugs: HashMap<(Option<DomainName>, Slug), usize> = HashMap::new();
+
+    for (index, record) in records.iter().enumerate() {
+        let row = index + 1;
+        let request = match parse_row(record, default_domain) {
+            Ok(request) => request,
+            Err(message) => {
+                plan.errors.push(RowError { row, message });
+                continue;
+            }
+        };
+
+        if let Some(slug) = &request.custom_slug
+            && let Some(first) = slugs.insert((request.domain.clone(), slug.clone()), row)
+        {
+            plan.errors.push(RowError {
+                row,
+                message: format!("slug {} is already used on row {}", slug, first),
+            });
+            continue;
+        }
+
+        plan.rows.push(ImportRow { row, request });
+    }
+
+    Ok(plan)
+}
+
+/// Shorten every row of a valid plan
+///
+/// `on_progress` is called once per finished row, from the worker threads.
+///
+/// # Errors
+///
+/// Returns [`Error::GenericError`] without sending anything if the plan has
+/// invalid rows.
+pub fn run<F>(
+    client: &Client,
+    plan: ImportPlan,
+    options: &BatchOptions,
+    on_progress: F,
+) -> Result<ImportReport>
+where
+    F: Fn() + Sync,
+{
+    if let Some(first) = plan.errors.first() {
+        return Err(Error::GenericError(format!(
+            "{} invalid rows, first {}",
+            plan.errors.len(),
+            first
+        )));
+    }
+
+    let requests = plan.rows.iter().map(|row| row.request.clone()).collect();
+    let batch = run_batch(requests, options, |request| {
+        let result = client.shorten(request);
+        on_progress();
+        result
+    });
+
+    let outcomes = plan
+        .rows
+        .into_iter()
+        .zip(batch.results)
+        .map(|(row, result)| ImportOutcome { row, result })
+        .collect();
+
+    Ok(ImportReport {
+        outcomes,
+        summary: batch.summary,
+    })
+}
+
+/// A row as column name (lowercase) to non-empty value
+type Record = BTreeMap<String, String>;
+
+fn csv_error(e: csv::Error) -> Error {
+    Error::GenericError(format!("invalid CSV: {}", e))
+}
+
+fn read_csv<R: Read>(reader: R) -> Result<Vec<Record>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(reader);
+
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(csv_error)?
+        .iter()
+        .map(str::to_ascii_lowercase)
+        .collect();
+
+    reader
+        .records()
+        .map(|record| {
+            let record = record.map_err(csv_error)?;
+            Ok(headers
+                .iter()
+                .zip(record.iter())
+                .filter(|(_, value)| !value.is_empty())
+                .map(|(name, value)| (name.clone(), value.to_string()))
+                .collect())
+        })
+        .collect()
+}
+
+fn read_json<R: Read>(reader: R) -> Result<Vec<Record>> {
+    let rows: Vec<serde_json::Map<String, Value>> = serde_json::from_reader(reader)?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .filter_map(|(name, value)| {
+                    let value = json_value(value)?;
+                    (!value.is_empty()).then(|| (name.to_ascii_lowercase(), value))
+                })
+                .collect()
+        })
+        .collect())
+}
+
+/// Flatten a JSON value into the text a CSV cell would hold
+fn json_value(value: Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) => Some(s.trim().to_string()),
+        Value::Array(items) => Some(
+            items
+                .into_iter()
+                .filter_map(json_value)
+                .collect::<Vec<_>>()
+                .join(";"),
+        ),
+        other => Some(other.to_string()),
+    }
+}
+
+/// First value found under any of the column names
+fn field<'a>(record: &'a Record, names: &[&str]) -> Option<&'a str> {
+    names
+        .iter()
+        .find_map(|name| record.get(*name))
+        .map(String::as_str)
+}
+
+fn parse_row(
+    record: &Record,
+    default_domain: &DomainName,
+) -> std::result::Result<ShortenRequest, String> {
+    let target_url = field(record, &["url", "target_url"]).ok_or("missing url")?;
+    let target_url = parse_url(target_url)?;
+
+    let custom_slug = field(record, &["slug", "custom_slug"])
+        .map(Slug::custom)
+        .transpose()
+        .map_err(|e| e.to_string())?;
+
+    let domain = field(record, &["domain"])
+        .map(DomainName::new)
+        .transpose()
+        .map_err(|e| e.to_string())?
+        .unwrap_or_else(|| default_domain.clone());
+
+    let tag_ids = field(record, &["tags", "tag_ids"])
+        .map(parse_tags)
+        .transpose()?;
+
+    let expire_at = field(record, &["expire_at", "expires"])
+        .map(parse_expire_at)
+        .transpose()?;
+
+    let expiration_redirect_url = field(record, &["expiration_redirect_url"])
+        .map(parse_url)
+        .transpose()?;
+
+    Ok(ShortenRequest {
+        target_url,
+        domain: Some(domain),
+        title: field(record, &["title"]).map(str::to_string),
+        custom_slug,
+        expiration_redirect_url,
+        expire_at,
+        password: field(record, &["password"]).map(Secret::new),
+        tag_ids,
+    })
+}
+
+fn parse_url(url: &str) -> std::result::Result<String, String> {
+    let parsed = Url::parse(url).map_err(|e| format!("invalid url {:?}: {}", url, e))?;
+
+    match parsed.scheme() {
+        "http" | "https" => Ok(parsed.into()),
+        scheme => Err(format!("unsupported url scheme {:?}", scheme)),
+    }
+}
+
+fn parse_tags(tags: &str) -> std::result::Result<Vec<TagId>, String> {
+    tags.split(|c: char| c == ';' || c == ',' || c.is_whitespace())
+        .filter(|tag| !tag.is_empty())
+        .map(|tag| tag.parse::<TagId>().map_err(|e| e.to_string()))
+        .collect()
+}
+
+/// Unix epoch seconds, an RFC 3339 timestamp or a plain `YYYY-MM-DD` date
+fn parse_expire_at(value: &str) -> std::result::Result<i64, String> {
+    if let Ok(secs) = value.parse() {
+        return Ok(secs);
+    }
+
+    let timestamp = if value.len() == 10 {
+        format!("{}T00:00:00Z", value)
+    } else {
+        value.to_string()
+    };
+
+    humantime::parse_rfc3339_weak(&timestamp)
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|since| since.as_secs() as i64)
+        .ok_or_else(|| format!("invalid expiration {:?}", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const CSV: &str = "\
+URL,Slug,Title,Tags,Expire_At,Notes
+https://example.com/a,spring-a,Spring A,1;2,2027-01-01,ignored
+https://example.com/b,,,,1800000000,
+";
+
+    #[test]
+    fn test_plan_maps_csv_columns() {
+        let plan = plan(CSV.as_bytes(), ImportFormat::Csv, &DomainName::default()).unwrap();
+
+        assert!(plan.is_valid(), "{:?}", plan.errors);
+        assert_eq!(plan.len(), 2);
+
+        let first = &plan.rows[0].request;
+        assert_eq!(first.target_url, "https://example.com/a");
+        assert_eq!(first.custom_slug.as_ref().unwrap(), "spring-a");
+        assert_eq!(first.title.as_deref(), Some("Spring A"));
+        assert_eq!(first.tag_ids, Some(vec![TagId::new(1), TagId::new(2)]));
+        assert_eq!(first.expire_at, Some(1_798_761_600));
+        assert_eq!(first.domain.clone().unwrap(), DomainName::default());
+
+        let second = &plan.rows[1];
+        assert_eq!(second.row, 2);
+        assert!(second.request.custom_slug.is_none());
+        assert!(second.request.title.is_none());
+        assert_eq!(second.request.expire_at, Some(1_800_000_000));
+    }
+
+    #[test]
+    fn test_plan_reads_json() {
+        let json = r#"[
+            {"target_url": "https://example.com/", "tag_ids": [3, 4], "domain": "s.ee"},
+            {"url": "https://example.org/", "password": "hunter2", "title": null}
+        ]"#;
+        let default_domain = DomainName::new("example.link").unwrap();
+        let plan = plan(json.as_bytes(), ImportFormat::Json, &default_domain).unwrap();
+
+        assert!(plan.is_valid(), "{:?}", plan.errors);
+        assert_eq!(plan.rows[0].request.tag_ids.as_ref().unwrap().len(), 2);
+        assert_eq!(plan.rows[0].request.domain.clone().unwrap(), "s.ee");
+        assert_eq!(plan.rows[1].request.domain, Some(default_domain));
+        assert_eq!(
+            plan.rows[1].request.password.as_ref().unwrap().expose(),
+            "hunter2"
+        );
+    }
+
+    #[test]
+    fn test_plan_collects_every_invalid_row() {
+        let csv = "\
+url,slug,tags,expire_at
+ftp://example.com/,,,
+https://example.com/a,not valid!,,
+https://example.com/b,dup,,
+https://example.com/c,dup,,
+https://example.com/d,,x,
+https://example.com/e,,,tomorrow
+,,,
+";
+        let plan = plan(csv.as_bytes(), ImportFormat::Csv, &DomainName::default()).unwrap();
+        let rows: Vec<usize> = plan.errors.iter().map(|e| e.row).collect();
+
+        assert_eq!(rows, [1, 2, 4, 5, 6, 7]);
+        assert!(plan.errors[2].message.contains("row 3"));
+        assert_eq!(plan.len(), 1);
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(
+            ImportFormat::from_path("links.CSV"),
+            Some(ImportFormat::Csv)
+        );
+        assert_eq!(
+            ImportFormat::from_path("links.json"),
+            Some(ImportFormat::Json)
+        );
+        assert_eq!(ImportFormat::from_path("links"), None);
+    }
+
+    #[test]
+    fn test_run_writes_report() {
+        let client = Client::new(Config::default().with_dry_run(true)).unwrap();
+        let plan = plan(CSV.as_bytes(), ImportFormat::Csv, &DomainName::default()).unwrap();
+        let progress = AtomicUsize::new(0);
+
+        let report = run(&client, plan, &BatchOptions::default(), || {
+            progress.fetch_add(1, Ordering::SeqCst);
+        })
+        .unwrap();
+
+        assert!(report.is_success());
+        assert_eq!(progress.load(Ordering::SeqCst), 2);
+
+        let mut csv = Vec::new();
+        report.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines[0], "row,target_url,slug,short_url,error");
+        assert!(lines[1].starts_with("1,https://example.com/a,spring-a,https://"));
+        assert_eq!(lines.len(), 3);
+    }
+
+    #[test]
+    fn test_run_refuses_invalid_plan() {
+        let client = Client::new(Config::default().with_dry_run(true)).unwrap();
+        let plan = plan(
+            "url\nnot a url\n".as_bytes(),
+            ImportFormat::Csv,
+            &DomainName::default(),
+        )
+        .unwrap();
+
+        let result = run(&client, plan, &BatchOptions::default(), || {});
+        assert!(matches!(result, Err(Error::GenericError(_))));
+    }
+}
@@ -0,0 +1,452 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: ledger.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-19 17:20:36
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 07:09:18
+ */
+
+//! Local JSON-lines record of every resource the SDK created, updated or
+//! deleted.
+//!
+//! The service has no list API, so the ledger is the only way to find
+//! created links, texts and files (and file delete URLs) later on. Enable it
+//! with [`Config::with_ledger`](crate::config::Config::with_ledger).
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::file::FileService;
+use crate::text::TextService;
+use crate::text::models::DeleteTextRequest;
+use crate::types::{DomainName, FileKey, Slug, TagId};
+use crate::url::ShortenService;
+use crate::url::models::DeleteRequest;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// What happened to a resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerAction {
+    Create,
+    Update,
+    Delete,
+}
+
+/// A resource created through the SDK
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CreatedResource {
+    /// A short link
+    Link { domain: DomainName, slug: Slug },
+
+    /// A text sharing entry
+    Text { domain: DomainName, slug: Slug },
+
+    /// An uploaded file
+    File { key: FileKey },
+}
+
+/// The kind of a resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceKind {
+    Link,
+    Text,
+    File,
+}
+
+impl CreatedResource {
+    /// The kind of the resource
+    pub fn kind(&self) -> ResourceKind {
+        match self {
+            CreatedResource::Link { .. } => ResourceKind::Link,
+            CreatedResource::Text { .. } => ResourceKind::Text,
+            CreatedResource::File { .. } => ResourceKind::File,
+        }
+    }
+
+    /// The domain of the resource, files have none
+    pub fn domain(&self) -> Option<&DomainName> {
+        match self {
+            CreatedResource::Link { domain, .. } | CreatedResource::Text { domain, .. } => {
+                Some(domain)
+            }
+            CreatedResource::File { .. } => None,
+        }
+    }
+
+    /// Delete the resource with the matching delete call
+    ///
+    /// A resource the service no longer knows fails with
+    /// [`Error::NotFound`].
+    pub fn delete(&self, client: &Client) -> Result<()> {
+        match self {
+            CreatedResource::Link { domain, slug } => {
+                client.delete(DeleteRequest {
+                    domain: domain.clone(),
+                    slug: slug.clone(),
+                })?;
+            }
+            CreatedResource::Text { domain, slug } => {
+                client.delete_text(DeleteTextRequest {
+                    domain: domain.clone(),
+                    slug: slug.clone(),
+                })?;
+            }
+            CreatedResource::File { key } => {
+                // The service answers for a file that is already gone with
+                // `success: false` rather than a 404
+                if !client.delete_file(key)?.success {
+                    return Err(Error::NotFound);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A single ledger record
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    /// When the action happened (Unix epoch seconds)
+    pub timestamp: i64,
+
+    /// What happened
+    pub action: LedgerAction,
+
+    /// The affected resource
+    pub resource: CreatedResource,
+
+    /// The full short URL, or file URL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_url: Option<String>,
+
+    /// The target URL of a link
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_url: Option<String>,
+
+    /// The title of a link or text, or the file name of a file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Tags attached at creation
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tag_ids: Vec<TagId>,
+
+    /// Expiration timestamp (Unix epoch)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expire_at: Option<i64>,
+
+    /// Deletion URL returned for uploaded files
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete_url: Option<String>,
+}
+
+impl LedgerEntry {
+    /// Create an entry for the given action, timestamped now
+    pub fn new(action: LedgerAction, resource: CreatedResource) -> Self {
+        Self {
+            timestamp: now(),
+            action,
+            resource,
+            short_url: None,
+            target_url: None,
+            title: None,
+            tag_ids: Vec::new(),
+            expire_at: None,
+            delete_url: None,
+        }
+    }
+
+    /// Merge a later update of the same resource into this entry
+    fn apply_update(&mut self, update: &LedgerEntry) {
+        if update.target_url.is_some() {
+            self.target_url.clone_from(&update.target_url);
+        }
+        if update.title.is_some() {
+            self.title.clone_from(&update.title);
+        }
+    }
+}
+
+/// Filter for ledger entries; unset fields match everything
+#[derive(Debug, Clone, Default)]
+pub struct LedgerQuery {
+    kind: Option<ResourceKind>,
+    action: Option<LedgerAction>,
+    tag: Option<TagId>,
+    domain: Option<DomainName>,
+    since: Option<i64>,
+    until: Option<i64>,
+}
+
+impl LedgerQuery {
+    /// Create a query matching every entry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match the given resource kind
+    pub fn with_kind(mut self, kind: ResourceKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    /// Only match the given action
+    pub fn with_action(mut self, action: LedgerAction) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    /// Only match entries carrying the given tag
+    pub fn with_tag(mut self, tag: TagId) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// Only match resources on the given domain
+    pub fn with_domain(mut self, domain: DomainName) -> Self {
+        self.domain = Some(domain);
+        self
+    }
+
+    /// Only match entries recorded at or after the timestamp (Unix epoch)
+    pub fn since(mut self, timestamp: i64) -> Self {
+        self.since = Some(timestamp);
+        self
+    }
+
+    /// Only match entries recorded before the timestamp (Unix epoch)
+    pub fn until(mut self, timestamp: i64) -> Self {
+        self.until = Some(timestamp);
+        self
+    }
+
+    /// Whether an entry matches the query
+    pub fn matches(&self, entry: &LedgerEntry) -> bool {
+        self.kind.is_none_or(|kind| entry.resource.kind() == kind)
+            && self.action.is_none_or(|action| entry.action == action)
+            && self.tag.is_none_or(|tag| entry.tag_ids.contains(&tag))
+            && self
+                .domain
+                .as_ref()
+                .is_none_or(|domain| entry.resource.domain() == Some(domain))
+            && self.since.is_none_or(|since| entry.timestamp >= since)
+            && self.until.is_none_or(|until| entry.timestamp < until)
+    }
+}
+
+/// Append-only JSON-lines ledger file
+#[derive(Debug)]
+pub struct Ledger {
+    path: PathBuf,
+    write_lock: Mutex<()>,
+}
+
+impl Ledger {
+    /// Open the ledger at `path`; the file is created on the first append
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+
+        Ok(Self {
+            path,
+            write_lock: Mutex::new(()),
+        })
+    }
+
+    /// The path of the ledger file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append an entry
+    pub fn append(&self, entry: &LedgerEntry) -> Result<()> {
+        let mut line = serde_json::to_string(entry)?;
+        line.push('\n');
+
+        let _guard = self.write_lock.lock().unwrap_or_else(|e| e.into_inner());
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        file.write_all(line.as_bytes())?;
+        Ok(())
+    }
+
+    /// Read all entries in the order they were recorded
+    pub fn entries(&self) -> Result<Vec<LedgerEntry>> {
+        let file = match fs::File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut entries = Vec::new();
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                entries.push(serde_json::from_str(&line)?);
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// Read the entries matching a query
+    pub fn query(&self, query: &LedgerQuery) -> Result<Vec<LedgerEntry>> {
+        Ok(self
+            .entries()?
+            .into_iter()
+            .filter(|entry| query.matches(entry))
+            .collect())
+    }
+
+    /// Resources that were created and not deleted since, as their create
+    /// entry with later updates merged in
+    pub fn live(&self) -> Result<Vec<LedgerEntry>> {
+        let mut order = Vec::new();
+        let mut live: HashMap<CreatedResource, LedgerEntry> = HashMap::new();
+
+        for entry in self.entries()? {
+            match entry.action {
+                LedgerAction::Create => {
+                    order.push(entry.resource.clone());
+                    live.insert(entry.resource.clone(), entry);
+                }
+                LedgerAction::Update => {
+                    if let Some(created) = live.get_mut(&entry.resource) {
+                        created.apply_update(&entry);
+                    }
+                }
+                LedgerAction::Delete => {
+                    live.remove(&entry.resource);
+                }
+            }
+        }
+
+        order.dedup();
+        Ok(order
+            .into_iter()
+            .filter_map(|resource| live.remove(&resource))
+            .collect())
+    }
+}
+
+/// Current time in Unix epoch seconds
+pub(crate) fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn temp_ledger(name: &str) -> Ledger {
+        let mut path = env::temp_dir();
+        path.push(format!("see-sdk-{}-{}.jsonl", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+        Ledger::open(path).unwrap()
+    }
+
+    fn link(slug: &str) -> CreatedResource {
+        CreatedResource::Link {
+            domain: DomainName::default(),
+            slug: Slug::new(slug).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_append_and_query() -> Result<()> {
+        let ledger = temp_ledger("ledger-query");
+
+        let mut tagged = LedgerEntry::new(LedgerAction::Create, link("first"));
+        tagged.tag_ids = vec![TagId::new(3)];
+        tagged.timestamp = 100;
+        ledger.append(&tagged)?;
+
+        let mut file = LedgerEntry::new(
+            LedgerAction::Create,
+            CreatedResource::File {
+                key: FileKey::new("abc")?,
+            },
+        );
+        file.timestamp = 200;
+        ledger.append(&file)?;
+
+        assert_eq!(ledger.entries()?.len(), 2);
+        assert_eq!(
+            ledger.query(&LedgerQuery::new().with_kind(ResourceKind::File))?,
+            vec![file.clone()]
+        );
+        assert_eq!(
+            ledger.query(&LedgerQuery::new().with_tag(TagId::new(3)))?,
+            vec![tagged.clone()]
+        );
+        assert_eq!(
+            ledger.query(&LedgerQuery::new().with_domain(DomainName::default()))?,
+            vec![tagged]
+        );
+        assert_eq!(
+            ledger.query(&LedgerQuery::new().since(150).until(250))?,
+            vec![file]
+        );
+
+        let _ = fs::remove_file(ledger.path());
+        Ok(())
+    }
+
+    #[test]
+    fn test_live_resources() -> Result<()> {
+        let ledger = temp_ledger("ledger-live");
+
+        ledger.append(&LedgerEntry::new(LedgerAction::Create, link("kept")))?;
+        ledger.append(&LedgerEntry::new(LedgerAction::Create, link("gone")))?;
+
+        let mut update = LedgerEntry::new(LedgerAction::Update, link("kept"));
+        update.target_url = Some("https://example.com/new".to_string());
+        ledger.append(&update)?;
+        ledger.append(&LedgerEntry::new(LedgerAction::Delete, link("gone")))?;
+
+        let live = ledger.live()?;
+        assert_eq!(live.len(), 1);
+        assert_eq!(live[0].resource, link("kept"));
+        assert_eq!(live[0].action, LedgerAction::Create);
+        assert_eq!(
+            live[0].target_url.as_deref(),
+            Some("https://example.com/new")
+        );
+
+        let _ = fs::remove_file(ledger.path());
+        Ok(())
+    }
+
+    #[test]
+    fn test_created_resource_serialization() {
+        let json = serde_json::to_value(CreatedResource::File {
+            key: FileKey::new("abc123").unwrap(),
+        })
+        .unwrap();
+        assert_eq!(json, serde_json::json!({"type": "file", "key": "abc123"}));
+    }
+}
@@ -9,7 +9,7 @@
  * File Created: 2025-10-23 11:07:28
  *
  * Modified By: S.EE Development Team <dev@s.ee>
- * Last Modified: 2026-01-19 23:44:40
+ * Last Modified: 2026-10-20 05:41:12
  */
 
 //! A Rust SDK for content sharing services (URL, Text, File).
@@ -36,21 +36,48 @@
 //! println!("Shortened URL: {}", response.data.short_url);
 //! ```
 
+pub mod batch;
+
 pub mod client;
 
 pub mod error;
 
+#[cfg(feature = "export")]
+pub mod export;
+
 pub mod url;
 
 pub mod config;
 
+pub mod credentials;
+
 pub mod tag;
 
 pub mod domain;
 
+pub mod doctor;
+
 pub mod file;
 
+pub mod gc;
+
+#[cfg(feature = "import")]
+pub mod import;
+
+pub mod ledger;
+
+pub mod registry;
+
+mod telemetry;
+
 pub mod text;
 
+pub mod transaction;
+
+pub mod types;
+
+#[cfg(feature = "watch")]
+pub mod watch;
+
 #[cfg(test)]
 mod test_helpers;
@@ -0,0 +1,252 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: registry.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-19 21:15:09
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 07:44:22
+ */
+
+//! Clients for several accounts sharing HTTP connection pools.
+//!
+//! Profiles without HTTP options share the registry's HTTP client, and
+//! profiles with the same HTTP options (proxy, root certificates and so on)
+//! share one built from those options.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use see_sdk::config::Config;
+//! use see_sdk::registry::ClientRegistry;
+//!
+//! let registry = ClientRegistry::new()?
+//!     .with_profile("marketing", Config::default().with_api_key("marketing-key"))?
+//!     .with_profile("support", Config::default().with_api_key("support-key"))?;
+//!
+//! let client = registry.client("marketing")?;
+//! # Ok::<(), see_sdk::error::Error>(())
+//! ```
+
+use crate::client::Client;
+use crate::config::{Config, ConfigFile, HttpOptions};
+use crate::error::{Error, Result};
+use reqwest::blocking::Client as HttpClient;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Named clients built on a shared HTTP client
+#[derive(Debug, Clone)]
+pub struct ClientRegistry {
+    http_client: HttpClient,
+    /// HTTP clients built for profiles with their own HTTP options
+    option_clients: Vec<(HttpOptions, HttpClient)>,
+    clients: BTreeMap<String, Client>,
+}
+
+impl ClientRegistry {
+    /// Create an empty registry with a new HTTP client
+    pub fn new() -> Result<Self> {
+        Ok(Self::with_http_client(HttpClient::builder().build()?))
+    }
+
+    /// Create an empty registry whose clients share `http_client`
+    pub fn with_http_client(http_client: HttpClient) -> Self {
+        Self {
+            http_client,
+            option_clients: Vec::new(),
+            clients: BTreeMap::new(),
+        }
+    }
+
+    /// Create a registry with a client for every profile of a config file
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let file = ConfigFile::load(path)?;
+        let mut registry = Self::new()?;
+
+        for name in file.profile_names() {
+            registry.insert(name, file.profile(name)?)?;
+        }
+
+        Ok(registry)
+    }
+
+    /// Add a profile
+    pub fn with_profile(mut self, name: impl Into<String>, config: Config) -> Result<Self> {
+        self.insert(name, config)?;
+        Ok(self)
+    }
+
+    /// Add a profile, replacing any profile of the same name
+    ///
+    /// The profile uses its own `http_client` if set. Otherwise it shares
+    /// the registry's HTTP client when it has no HTTP options, or one built
+    /// from its HTTP options.
+    pub fn insert(&mut self, name: impl Into<String>, config: Config) -> Result<()> {
+        let http_client = match &config.http_client {
+            Some(http_client) => http_client.clone(),
+            None if config.http == HttpOptions::default() => self.http_client.clone(),
+            None => self.http_client_for(&config.http)?,
+        };
+
+        let client = Client::with_http_client(config, http_client)?;
+        self.clients.insert(name.into(), client);
+        Ok(())
+    }
+
+    /// The HTTP client for the given options, building it on first use
+    fn http_client_for(&mut self, options: &HttpOptions) -> Result<HttpClient> {
+        if let Some((_, http_client)) = self
+            .option_clients
+            .iter()
+            .find(|(existing, _)| existing == options)
+        {
+            return Ok(http_client.clone());
+        }
+
+        let http_client = options.build_client()?;
+        self.option_clients
+            .push((options.clone(), http_client.clone()));
+        Ok(http_client)
+    }
+
+    /// Remove a profile, returning its client
+    pub fn remove(&mut self, name: &str) -> Option<Client> {
+        self.clients.remove(name)
+    }
+
+    /// The client of a profile
+    pub fn client(&self, name: &str) -> Result<Client> {
+        self.clients
+            .get(name)
+            .cloned()
+            .ok_or_else(|| Error::ConfigError(format!("no profile named {:?}", name)))
+    }
+
+    /// Names of the registered profiles, sorted
+    pub fn profile_names(&self) -> impl Iterator<Item = &str> {
+        self.clients.keys().map(String::as_str)
+    }
+
+    /// Number of registered profiles
+    pub fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// Whether no profile is registered
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+    use std::time::Duration;
+
+    #[test]
+    fn test_registry_profiles() -> Result<()> {
+        let mut registry = ClientRegistry::new()?
+            .with_profile("marketing", Config::default().with_api_key("m"))?
+            .with_profile(
+                "support",
+                Config::default()
+                    .with_api_key("s")
+                    .with_timeout(Duration::from_secs(5)),
+            )?;
+
+        assert_eq!(
+            registry.profile_names().collect::<Vec<_>>(),
+            ["marketing", "support"]
+        );
+        assert_eq!(
+            registry
+                .client("marketing")?
+                .config()
+                .api_key
+                .clone()
+                .unwrap(),
+            "m"
+        );
+        assert_eq!(
+            registry.client("support")?.config().timeout,
+            Duration::from_secs(5)
+        );
+        assert!(matches!(
+            registry.client("engineering"),
+            Err(Error::ConfigError(_))
+        ));
+
+        assert!(registry.remove("marketing").is_some());
+        assert_eq!(registry.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_registry_from_file() -> Result<()> {
+        let mut path = env::temp_dir();
+        path.push(format!("see-sdk-registry-{}.toml", std::process::id()));
+        fs::write(
+            &path,
+            "[profiles.marketing]\napi_key = \"m\"\n\n[profiles.engineering]\napi_key = \"e\"\n",
+        )?;
+
+        let registry = ClientRegistry::from_file(&path)?;
+        assert_eq!(
+            registry.profile_names().collect::<Vec<_>>(),
+            ["engineering", "marketing"]
+        );
+        assert_eq!(
+            registry
+                .client("engineering")?
+                .config()
+                .api_key
+                .clone()
+                .unwrap(),
+            "e"
+        );
+
+        let _ = fs::remove_file(path);
+        Ok(())
+    }
+
+    #[test]
+    fn test_registry_honors_http_options() -> Result<()> {
+        let file: ConfigFile = r#"
+            [profiles.plain]
+            api_key = "p"
+
+            [profiles.proxied]
+            api_key = "a"
+            proxy = "http://127.0.0.1:3128"
+            connect_timeout = 3
+
+            [profiles.also_proxied]
+            api_key = "b"
+            proxy = "http://127.0.0.1:3128"
+            connect_timeout = 3
+        "#
+        .parse()
+        .unwrap();
+
+        let mut registry = ClientRegistry::new()?;
+        for name in file.profile_names() {
+            registry.insert(name, file.profile(name)?)?;
+        }
+        assert_eq!(registry.len(), 3);
+        assert_eq!(registry.option_clients.len(), 1);
+
+        // Options that can't be honored fail instead of being dropped
+        let config = Config::default().with_http_options(
+            HttpOptions::new().with_root_certificate("/nonexistent/see-sdk-ca.pem"),
+        );
+        assert!(registry.insert("broken", config).is_err());
+        Ok(())
+    }
+}
@@ -9,20 +9,21 @@
  * File Created: 2025-10-23 17:25:13
  *
  * Modified By: S.EE Development Team <dev@s.ee>
- * Last Modified: 2026-02-03 10:27:35
+ * Last Modified: 2026-10-19 11:06:12
  */
 
 use std::str;
 
 use crate::client::Client;
 use crate::error::Result;
+use crate::types::TagId;
 use serde::{Deserialize, Serialize};
 
 /// Represents a tag
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Tag {
     /// Unique identifier for the tag
-    pub id: u32,
+    pub id: TagId,
 
     /// Name of the tag
     pub name: String,
@@ -73,7 +74,7 @@ mod tests {
         // Verify each tag has valid data
         for tag in &response.data.tags {
             assert!(!tag.name.is_empty(), "Tag name should not be empty");
-            assert_ne!(tag.id, 0, "Tag ID should not be zero");
+            assert_ne!(tag.id.get(), 0, "Tag ID should not be zero");
         }
 
         Ok(())
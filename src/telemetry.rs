@@ -0,0 +1,432 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: telemetry.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-20 05:24:58
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 05:58:03
+ */
+
+//! Instrumentation of API calls.
+//!
+//! With the `tracing` feature every call runs in a `see_request` span with
+//! these fields:
+//!
+//! | Field | Value |
+//! |-------|-------|
+//! | `method` | HTTP method |
+//! | `path` | API path, such as `/shorten` |
+//! | `attempt` | Retry attempt of the last response, 0 for the first try |
+//! | `status` | HTTP status of the last response |
+//! | `request_id` | Request ID header of the last response, if any |
+//! | `latency_ms` | Time for the whole call, retries included |
+//!
+//! Retries and failed attempts are reported as events inside the span.
+//! Headers and bodies are never recorded, so neither are the API key nor
+//! any password.
+//!
+//! With the `metrics` feature the same calls are reported to whatever
+//! recorder the application installed through the `metrics` crate:
+//!
+//! | Metric | Type | Labels |
+//! |--------|------|--------|
+//! | `see_requests_total` | counter | `method`, `endpoint`, `outcome` |
+//! | `see_request_duration_seconds` | histogram | `method`, `endpoint`, `outcome` |
+//! | `see_request_retries_total` | counter | `endpoint`, `reason` |
+//! | `see_rate_limited_total` | counter | `endpoint` |
+//! | `see_uploaded_bytes_total` | counter | |
+//!
+//! `outcome` is one of `success`, `client_error`, `server_error`,
+//! `rate_limited`, `network_error` or `error` when no request was sent.
+//! `endpoint` is the API path with keys cut off, so `/file/delete/{key}` is
+//! counted as `/file/delete`.
+//!
+//! Without either feature this compiles to nothing.
+
+use reqwest::Method;
+use reqwest::blocking::Response;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "metrics")]
+use std::cell::Cell;
+
+/// Response headers that may carry the service's request ID
+#[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+const REQUEST_ID_HEADERS: &[&str] = &["x-request-id", "request-id", "x-correlation-id"];
+
+/// API paths that end in a key, reported without it
+#[cfg_attr(not(feature = "metrics"), allow(dead_code))]
+const KEYED_ENDPOINTS: &[&str] = &["/file/delete/"];
+
+/// Instruments one API call, across its retries
+#[cfg_attr(not(any(feature = "tracing", feature = "metrics")), allow(dead_code))]
+pub(crate) struct RequestTelemetry {
+    started: Instant,
+
+    #[cfg(feature = "tracing")]
+    span: tracing::span::EnteredSpan,
+
+    #[cfg(feature = "metrics")]
+    method: String,
+
+    #[cfg(feature = "metrics")]
+    endpoint: String,
+
+    #[cfg(feature = "metrics")]
+    outcome: Cell<&'static str>,
+}
+
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+impl RequestTelemetry {
+    /// Start instrumenting a call, entering its span
+    pub(crate) fn start(method: &Method, path: &str) -> Self {
+        Self {
+            started: Instant::now(),
+
+            #[cfg(feature = "metrics")]
+            method: method.to_string(),
+
+            #[cfg(feature = "metrics")]
+            endpoint: endpoint(path).to_string(),
+
+            #[cfg(feature = "metrics")]
+            outcome: Cell::new("error"),
+
+            #[cfg(feature = "tracing")]
+            span: tracing::info_span!(
+                "see_request",
+                method = %method,
+                path,
+                attempt = 0u32,
+                status = tracing::field::Empty,
+                request_id = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+            )
+            .entered(),
+        }
+    }
+
+    /// A response arrived for the given attempt
+    pub(crate) fn response(&self, attempt: u32, response: &Response) {
+        #[cfg(feature = "tracing")]
+        {
+            self.span.record("attempt", attempt);
+            self.span.record("status", response.status().as_u16());
+            if let Some(request_id) = request_id(response) {
+                self.span.record("request_id", request_id);
+            }
+            tracing::debug!(attempt, status = response.status().as_u16(), "response");
+        }
+
+        #[cfg(feature = "metrics")]
+        {
+            let status = response.status();
+            let outcome = if status.is_success() {
+                "success"
+            } else if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                metrics::counter!("see_rate_limited_total", "endpoint" => self.endpoint.clone())
+                    .increment(1);
+                "rate_limited"
+            } else if status.is_server_error() {
+                "server_error"
+            } else {
+                "client_error"
+            };
+            self.outcome.set(outcome);
+        }
+    }
+
+    /// The given attempt failed without a response
+    pub(crate) fn failure(&self, attempt: u32, error: &reqwest::Error) {
+        #[cfg(feature = "tracing")]
+        {
+            self.span.record("attempt", attempt);
+            tracing::warn!(attempt, error = %error, "request failed");
+        }
+
+        #[cfg(feature = "metrics")]
+        self.outcome.set("network_error");
+    }
+
+    /// The call is retried after `delay`
+    pub(crate) fn retry(&self, attempt: u32, delay: Duration, reason: &'static str) {
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            attempt,
+            delay_ms = delay.as_millis() as u64,
+            reason,
+            "retrying"
+        );
+
+        #[cfg(feature = "metrics")]
+        metrics::counter!(
+            "see_request_retries_total",
+            "endpoint" => self.endpoint.clone(),
+            "reason" => reason,
+        )
+        .increment(1);
+    }
+}
+
+impl Drop for RequestTelemetry {
+    fn drop(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.span
+            .record("latency_ms", self.started.elapsed().as_millis() as u64);
+
+        #[cfg(feature = "metrics")]
+        {
+            let labels = [
+                ("method", self.method.clone()),
+                ("endpoint", self.endpoint.clone()),
+                ("outcome", self.outcome.get().to_string()),
+            ];
+            metrics::counter!("see_requests_total", &labels).increment(1);
+            metrics::histogram!("see_request_duration_seconds", &labels)
+                .record(self.started.elapsed().as_secs_f64());
+        }
+    }
+}
+
+/// A file of `bytes` bytes was uploaded
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub(crate) fn record_upload(bytes: u64) {
+    #[cfg(feature = "metrics")]
+    metrics::counter!("see_uploaded_bytes_total").increment(bytes);
+}
+
+/// The API path without any trailing key
+#[cfg_attr(not(feature = "metrics"), allow(dead_code))]
+fn endpoint(path: &str) -> &str {
+    KEYED_ENDPOINTS
+        .iter()
+        .find(|prefix| path.starts_with(**prefix))
+        .map_or(path, |prefix| prefix.trim_end_matches('/'))
+}
+
+/// The request ID the service sent back, if any
+#[cfg(feature = "tracing")]
+fn request_id(response: &Response) -> Option<&str> {
+    REQUEST_ID_HEADERS
+        .iter()
+        .find_map(|name| response.headers().get(*name)?.to_str().ok())
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use crate::client::Client;
+    use crate::config::Config;
+    use crate::test_helpers::helpers::serve;
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Collects every recorded field as `name=value`
+    #[derive(Default, Clone)]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl Visit for Recorder {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("{}={:?}", field.name(), value));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("span={}", span.metadata().name()));
+            span.record(&mut self.clone());
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, values: &Record<'_>) {
+            values.record(&mut self.clone());
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            event.record(&mut self.clone());
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[test]
+    fn test_request_span_fields() {
+        let (base_url, server) = serve(vec![
+            "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 0\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            "HTTP/1.1 200 OK\r\nX-Request-Id: req-42\r\nContent-Type: application/json\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}",
+        ]);
+
+        let client = Client::new(
+            Config::new(base_url)
+                .with_api_key("hunter2")
+                .with_max_retries(1),
+        )
+        .unwrap();
+        let recorder = Recorder::default();
+
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let request = serde_json::json!({"password": "hunter2"});
+            let _: serde_json::Value = client
+                .execute_request(reqwest::Method::POST, "/shorten", request)
+                .unwrap();
+        });
+        server.join().unwrap();
+
+        let fields = recorder.0.lock().unwrap().join(" ");
+        for expected in [
+            "span=see_request",
+            "method=POST",
+            "path=\"/shorten\"",
+            "status=429",
+            "reason=\"rate limited\"",
+            "attempt=1",
+            "status=200",
+            "request_id=\"req-42\"",
+            "latency_ms=",
+        ] {
+            assert!(
+                fields.contains(expected),
+                "{} missing in {}",
+                expected,
+                fields
+            );
+        }
+        assert!(!fields.contains("hunter2"));
+    }
+}
+
+#[cfg(all(test, feature = "metrics"))]
+mod metrics_tests {
+    use super::*;
+    use crate::client::Client;
+    use crate::config::Config;
+    use crate::test_helpers::helpers::serve;
+    use metrics::{
+        Counter, CounterFn, Gauge, Histogram, HistogramFn, Key, KeyName, Metadata, SharedString,
+        Unit,
+    };
+    use std::sync::{Arc, Mutex};
+
+    /// Collects every update as `name{label=value,...} value`
+    #[derive(Default, Clone)]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    struct Handle {
+        key: String,
+        updates: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl CounterFn for Handle {
+        fn increment(&self, value: u64) {
+            self.updates
+                .lock()
+                .unwrap()
+                .push(format!("{} {}", self.key, value));
+        }
+
+        fn absolute(&self, _: u64) {}
+    }
+
+    impl HistogramFn for Handle {
+        fn record(&self, value: f64) {
+            assert!(value >= 0.0);
+            self.updates.lock().unwrap().push(self.key.clone());
+        }
+    }
+
+    impl Recorder {
+        fn handle(&self, key: &Key) -> Arc<Handle> {
+            let labels: Vec<String> = key
+                .labels()
+                .map(|label| format!("{}={}", label.key(), label.value()))
+                .collect();
+            Arc::new(Handle {
+                key: format!("{}{{{}}}", key.name(), labels.join(",")),
+                updates: self.0.clone(),
+            })
+        }
+    }
+
+    impl metrics::Recorder for Recorder {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+            Counter::from_arc(self.handle(key))
+        }
+
+        fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+            Gauge::noop()
+        }
+
+        fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+            Histogram::from_arc(self.handle(key))
+        }
+    }
+
+    #[test]
+    fn test_endpoint_without_key() {
+        assert_eq!(endpoint("/file/delete/abc123"), "/file/delete");
+        assert_eq!(endpoint("/shorten"), "/shorten");
+    }
+
+    #[test]
+    fn test_request_metrics() {
+        let (base_url, server) = serve(vec![
+            "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 0\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}",
+        ]);
+
+        let client = Client::new(
+            Config::new(base_url)
+                .with_api_key("hunter2")
+                .with_max_retries(1),
+        )
+        .unwrap();
+        let recorder = Recorder::default();
+
+        metrics::with_local_recorder(&recorder, || {
+            let _: serde_json::Value = client
+                .execute_request(reqwest::Method::POST, "/file/delete/abc123", ())
+                .unwrap();
+            record_upload(1024);
+        });
+        server.join().unwrap();
+
+        let updates = recorder.0.lock().unwrap().clone();
+        assert_eq!(
+            updates,
+            [
+                "see_rate_limited_total{endpoint=/file/delete} 1",
+                "see_request_retries_total{endpoint=/file/delete,reason=rate limited} 1",
+                "see_requests_total{method=POST,endpoint=/file/delete,outcome=success} 1",
+                "see_request_duration_seconds{method=POST,endpoint=/file/delete,outcome=success}",
+                "see_uploaded_bytes_total{} 1024",
+            ]
+        );
+    }
+}
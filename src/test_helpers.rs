@@ -9,31 +9,36 @@
  * File Created: 2025-10-24 07:27:39
  *
  * Modified By: S.EE Development Team <dev@s.ee>
- * Last Modified: 2026-02-03 10:28:41
+ * Last Modified: 2026-10-20 07:31:04
  */
 
 #[cfg(test)]
 pub mod helpers {
-    use crate::{client::Client, config::Config, error::Result};
-    use std::env;
+    use crate::{client::Client, config::Config, error::Result, types::Secret};
 
     /// Get API key from environment variable, skip test if not set
-    pub fn get_api_key_or_skip() -> Option<String> {
-        match env::var("SEE_API_KEY") {
-            Ok(key) if !key.is_empty() => Some(key),
-            _ => {
+    ///
+    /// Panics when the `SEE_*` variables are set but invalid, so a broken
+    /// environment fails the tests instead of skipping them.
+    pub fn get_api_key_or_skip() -> Option<Secret> {
+        let config = Config::from_env().expect("invalid SEE_* environment variables");
+
+        match config.api_key {
+            Some(key) => Some(key),
+            None => {
                 log::warn!("Skipping test: SEE_API_KEY not set");
                 None
             }
         }
     }
 
-    /// Create a test client with default configuration and API key from environment
+    /// Create a test client configured from the environment
     pub fn create_test_client() -> Result<Client> {
-        let api_key = get_api_key_or_skip()
-            .ok_or_else(|| crate::error::Error::InvalidUrl("No API key".to_string()))?;
+        let config = Config::from_env()?;
+        if config.api_key.is_none() {
+            return Err(crate::error::Error::ConfigError("No API key".to_string()));
+        }
 
-        let config = Config::default().with_api_key(&api_key);
         Client::new(config)
     }
 
@@ -46,4 +51,37 @@ pub mod helpers {
     pub fn assert_not_empty(value: &str) {
         assert!(!value.is_empty(), "Expected non-empty string");
     }
+
+    /// Serve the canned responses to consecutive connections, returning the
+    /// base URL and the Authorization header of each request
+    pub fn serve<R: AsRef<str> + Send + 'static>(
+        responses: Vec<R>,
+    ) -> (String, std::thread::JoinHandle<Vec<String>>) {
+        use std::io::{BufRead, BufReader, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = std::thread::spawn(move || {
+            let mut auth_headers = Vec::new();
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+                    if let Some(value) = line.strip_prefix("authorization: ") {
+                        auth_headers.push(value.trim().to_string());
+                    }
+                    line.clear();
+                }
+
+                stream.write_all(response.as_ref().as_bytes()).unwrap();
+            }
+            auth_headers
+        });
+
+        (base_url, handle)
+    }
 }
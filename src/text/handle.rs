@@ -0,0 +1,167 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: handle.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-19 09:21:05
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-19 17:01:47
+ */
+
+use crate::client::Client;
+use crate::error::Result;
+use crate::text::TextService;
+use crate::text::models::{
+    CreateTextData, DeleteTextRequest, DeleteTextResponse, UpdateTextRequest, UpdateTextResponse,
+};
+use crate::types::{DomainName, Slug};
+use url::Url;
+
+/// A text sharing entry bound to the client that manages it
+///
+/// Returned by [`TextService::create_text_handle`], or attached to an
+/// existing entry with [`TextHandle::new`].
+#[derive(Debug, Clone)]
+pub struct TextHandle {
+    client: Client,
+    domain: DomainName,
+    slug: Slug,
+    short_url: String,
+    title: String,
+}
+
+impl TextHandle {
+    /// Attach a handle to an existing text sharing entry
+    pub fn new(client: Client, domain: DomainName, slug: Slug, title: impl Into<String>) -> Self {
+        let short_url = format!("https://{}/{}", domain, slug);
+
+        Self {
+            client,
+            domain,
+            slug,
+            short_url,
+            title: title.into(),
+        }
+    }
+
+    /// Create a handle from the data returned by a create call
+    ///
+    /// The create response doesn't echo the domain, so it falls back to the
+    /// host of the short URL when the request didn't name one.
+    pub(crate) fn from_data(
+        client: Client,
+        domain: Option<DomainName>,
+        title: String,
+        data: CreateTextData,
+    ) -> Self {
+        let domain = domain
+            .or_else(|| domain_from_short_url(&data.short_url))
+            .unwrap_or_default();
+
+        Self {
+            client,
+            domain,
+            slug: data.slug,
+            short_url: data.short_url,
+            title,
+        }
+    }
+
+    /// The domain of the text entry
+    pub fn domain(&self) -> &DomainName {
+        &self.domain
+    }
+
+    /// The slug of the text entry
+    pub fn slug(&self) -> &Slug {
+        &self.slug
+    }
+
+    /// The full short URL
+    pub fn short_url(&self) -> &str {
+        &self.short_url
+    }
+
+    /// The current title of the text entry
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Replace the content, keeping the current title
+    pub fn update_content(&self, content: impl Into<String>) -> Result<UpdateTextResponse> {
+        self.client.update_text(UpdateTextRequest {
+            content: content.into(),
+            domain: self.domain.clone(),
+            slug: self.slug.clone(),
+            title: self.title.clone(),
+        })
+    }
+
+    /// Replace both the content and the title
+    pub fn update(
+        &mut self,
+        content: impl Into<String>,
+        title: impl Into<String>,
+    ) -> Result<UpdateTextResponse> {
+        let title = title.into();
+        let response = self.client.update_text(UpdateTextRequest {
+            content: content.into(),
+            domain: self.domain.clone(),
+            slug: self.slug.clone(),
+            title: title.clone(),
+        })?;
+
+        self.title = title;
+        Ok(response)
+    }
+
+    /// Delete the text entry, consuming the handle
+    pub fn delete(self) -> Result<DeleteTextResponse> {
+        self.client.delete_text(DeleteTextRequest {
+            domain: self.domain,
+            slug: self.slug,
+        })
+    }
+}
+
+/// Extract the host part of a short URL
+pub(crate) fn domain_from_short_url(short_url: &str) -> Option<DomainName> {
+    Url::parse(short_url)
+        .ok()
+        .and_then(|url| url.host_str().and_then(|host| DomainName::new(host).ok()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_from_short_url() {
+        assert_eq!(
+            domain_from_short_url("https://s.ee/abc123").unwrap(),
+            "s.ee"
+        );
+        assert!(domain_from_short_url("not-a-url").is_none());
+    }
+
+    #[test]
+    fn test_from_data_prefers_requested_domain() {
+        let client = Client::with_default_config().unwrap();
+        let data = CreateTextData {
+            custom_slug: None,
+            short_url: "https://txt.example/abc123".to_string(),
+            slug: Slug::new("abc123").unwrap(),
+        };
+
+        let text = TextHandle::from_data(client.clone(), None, "Title".into(), data.clone());
+        assert_eq!(text.domain(), "txt.example");
+
+        let text = TextHandle::from_data(client, Some(DomainName::default()), "Title".into(), data);
+        assert_eq!(text.domain(), "s.ee");
+        assert_eq!(text.title(), "Title");
+    }
+}
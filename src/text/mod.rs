@@ -9,22 +9,28 @@
  * File Created: 2026-01-19 23:39:17
  *
  * Modified By: S.EE Development Team <dev@s.ee>
- * Last Modified: 2026-01-20 00:01:09
+ * Last Modified: 2026-10-20 06:58:31
  */
 
 use crate::client::Client;
 use crate::error::Result;
+use crate::ledger::{CreatedResource, LedgerAction, LedgerEntry};
+use crate::text::handle::{TextHandle, domain_from_short_url};
 use crate::text::models::{
     CreateTextRequest, CreateTextResponse, DeleteTextRequest, DeleteTextResponse,
     TextDomainsResponse, UpdateTextRequest, UpdateTextResponse,
 };
 
+pub mod handle;
 pub mod models;
 
 pub trait TextService {
     /// Create a new text sharing entry
     fn create_text(&self, request: CreateTextRequest) -> Result<CreateTextResponse>;
 
+    /// Create a new text sharing entry and return a handle bound to it
+    fn create_text_handle(&self, request: CreateTextRequest) -> Result<TextHandle>;
+
     /// Update an existing text sharing entry
     fn update_text(&self, request: UpdateTextRequest) -> Result<UpdateTextResponse>;
 
@@ -37,18 +43,99 @@ pub trait TextService {
 
 impl TextService for Client {
     /// Create a new text sharing
-    fn create_text(&self, request: CreateTextRequest) -> Result<CreateTextResponse> {
-        self.execute_request(reqwest::Method::POST, "/text", request)
+    fn create_text(&self, mut request: CreateTextRequest) -> Result<CreateTextResponse> {
+        if request.domain.is_none() {
+            request.domain = self.config().default_domain.clone();
+        }
+
+        if self.is_dry_run() {
+            self.log_dry_run(reqwest::Method::POST, "/text", &request)?;
+            return Ok(CreateTextResponse::dry_run(
+                &request.domain.unwrap_or_default(),
+                request.custom_slug,
+            ));
+        }
+
+        let response: CreateTextResponse =
+            self.execute_request(reqwest::Method::POST, "/text", &request)?;
+
+        self.record(|| LedgerEntry {
+            short_url: Some(response.data.short_url.clone()),
+            title: Some(request.title),
+            tag_ids: request.tag_ids.unwrap_or_default(),
+            expire_at: request.expire_at,
+            ..LedgerEntry::new(
+                LedgerAction::Create,
+                CreatedResource::Text {
+                    domain: request
+                        .domain
+                        .or_else(|| domain_from_short_url(&response.data.short_url))
+                        .unwrap_or_default(),
+                    slug: response.data.slug.clone(),
+                },
+            )
+        });
+
+        Ok(response)
+    }
+
+    /// Create a new text sharing and bind the result to this client
+    fn create_text_handle(&self, request: CreateTextRequest) -> Result<TextHandle> {
+        let domain = request.domain.clone();
+        let title = request.title.clone();
+        let response = self.create_text(request)?;
+
+        Ok(TextHandle::from_data(
+            self.clone(),
+            domain,
+            title,
+            response.data,
+        ))
     }
 
     /// Update an existing text sharing
     fn update_text(&self, request: UpdateTextRequest) -> Result<UpdateTextResponse> {
-        self.execute_request(reqwest::Method::PUT, "/text", request)
+        if self.is_dry_run() {
+            self.log_dry_run(reqwest::Method::PUT, "/text", &request)?;
+            return Ok(UpdateTextResponse::dry_run());
+        }
+
+        let response = self.execute_request(reqwest::Method::PUT, "/text", &request)?;
+
+        self.record(|| LedgerEntry {
+            title: Some(request.title),
+            ..LedgerEntry::new(
+                LedgerAction::Update,
+                CreatedResource::Text {
+                    domain: request.domain,
+                    slug: request.slug,
+                },
+            )
+        });
+
+        Ok(response)
     }
 
     /// Delete a text sharing
     fn delete_text(&self, request: DeleteTextRequest) -> Result<DeleteTextResponse> {
-        self.execute_request(reqwest::Method::DELETE, "/text", request)
+        if self.is_dry_run() {
+            self.log_dry_run(reqwest::Method::DELETE, "/text", &request)?;
+            return Ok(DeleteTextResponse::dry_run());
+        }
+
+        let response = self.execute_request(reqwest::Method::DELETE, "/text", &request)?;
+
+        self.record(|| {
+            LedgerEntry::new(
+                LedgerAction::Delete,
+                CreatedResource::Text {
+                    domain: request.domain,
+                    slug: request.slug,
+                },
+            )
+        });
+
+        Ok(response)
     }
 
     /// Get available text domains
@@ -60,8 +147,8 @@ impl TextService for Client {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::DEFAULT_DOMAIN;
     use crate::test_helpers::helpers::{create_test_client, get_api_key_or_skip};
+    use crate::types::DomainName;
 
     #[test]
     fn test_text_lifecycle() {
@@ -100,8 +187,8 @@ mod tests {
             .short_url
             .split('/')
             .nth(2)
-            .unwrap_or(DEFAULT_DOMAIN)
-            .to_string();
+            .and_then(|host| DomainName::new(host).ok())
+            .unwrap_or_default();
 
         // 2. Update Text
         let update_req = UpdateTextRequest {
@@ -124,4 +211,37 @@ mod tests {
         let delete_resp = client.delete_text(delete_req).unwrap();
         assert_eq!(delete_resp.code, 200);
     }
+
+    #[test]
+    fn test_text_handle_lifecycle() {
+        if get_api_key_or_skip().is_none() {
+            return;
+        }
+
+        let client = create_test_client().unwrap();
+
+        let create_req = CreateTextRequest {
+            content: "Hello from Rust SDK handle".to_string(),
+            title: "Rust SDK Handle Test".to_string(),
+            domain: None,
+            custom_slug: None,
+            expire_at: None,
+            password: None,
+            tag_ids: None,
+            text_type: None,
+        };
+
+        let mut text = client.create_text_handle(create_req).unwrap();
+        assert!(!text.short_url().is_empty());
+
+        let update_resp = text.update_content("Updated content").unwrap();
+        assert!(update_resp.code == 0 || update_resp.code == 200);
+
+        let update_resp = text.update("Updated again", "Renamed").unwrap();
+        assert!(update_resp.code == 0 || update_resp.code == 200);
+        assert_eq!(text.title(), "Renamed");
+
+        let delete_resp = text.delete().unwrap();
+        assert_eq!(delete_resp.code, 200);
+    }
 }
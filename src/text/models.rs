@@ -9,9 +9,10 @@
  * File Created: 2026-01-19 23:39:03
  *
  * Modified By: S.EE Development Team <dev@s.ee>
- * Last Modified: 2026-02-03 10:32:25
+ * Last Modified: 2026-10-20 07:15:36
  */
 
+use crate::types::{DRY_RUN_MESSAGE, DRY_RUN_SLUG, DomainName, Secret, Slug, TagId};
 use serde::{Deserialize, Serialize};
 
 /// Request structure for creating text sharing
@@ -25,11 +26,11 @@ pub struct CreateTextRequest {
 
     /// Optional specific domain to use
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain: Option<String>,
+    pub domain: Option<DomainName>,
 
     /// Optional custom slug
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub custom_slug: Option<String>,
+    pub custom_slug: Option<Slug>,
 
     /// Optional expiration timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -37,11 +38,11 @@ pub struct CreateTextRequest {
 
     /// Optional password for protection
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub password: Option<String>,
+    pub password: Option<Secret>,
 
     /// Optional tag IDs
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tag_ids: Option<Vec<u32>>,
+    pub tag_ids: Option<Vec<TagId>>,
 
     /// Optional text type (e.g., syntax highlighting)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -51,9 +52,9 @@ pub struct CreateTextRequest {
 /// Data structure for created text
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct CreateTextData {
-    pub custom_slug: Option<String>,
+    pub custom_slug: Option<Slug>,
     pub short_url: String,
-    pub slug: String,
+    pub slug: Slug,
 }
 
 /// Response for creating text sharing
@@ -64,6 +65,25 @@ pub struct CreateTextResponse {
     pub data: CreateTextData,
 }
 
+impl CreateTextResponse {
+    /// Synthetic response for a text that was not actually sent
+    pub(crate) fn dry_run(domain: &DomainName, custom_slug: Option<Slug>) -> Self {
+        let slug = custom_slug
+            .clone()
+            .unwrap_or_else(|| Slug::new(DRY_RUN_SLUG).expect("valid dry-run slug"));
+
+        Self {
+            code: reqwest::StatusCode::OK.as_u16().into(),
+            message: DRY_RUN_MESSAGE.to_string(),
+            data: CreateTextData {
+                custom_slug,
+                short_url: format!("https://{}/{}", domain, slug),
+                slug,
+            },
+        }
+    }
+}
+
 /// Request structure for updating text sharing
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct UpdateTextRequest {
@@ -71,10 +91,10 @@ pub struct UpdateTextRequest {
     pub content: String,
 
     /// The domain of the shared text
-    pub domain: String,
+    pub domain: DomainName,
 
     /// The slug of the shared text
-    pub slug: String,
+    pub slug: Slug,
 
     /// The new title
     pub title: String,
@@ -88,14 +108,25 @@ pub struct UpdateTextResponse {
     pub data: serde_json::Value,
 }
 
+impl UpdateTextResponse {
+    /// Synthetic response for an update that was not actually sent
+    pub(crate) fn dry_run() -> Self {
+        Self {
+            code: reqwest::StatusCode::OK.as_u16().into(),
+            message: DRY_RUN_MESSAGE.to_string(),
+            data: serde_json::Value::Null,
+        }
+    }
+}
+
 /// Request structure for deleting text sharing
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct DeleteTextRequest {
     /// The domain of the shared text
-    pub domain: String,
+    pub domain: DomainName,
 
     /// The slug of the shared text to delete
-    pub slug: String,
+    pub slug: Slug,
 }
 
 /// Response for deleting text sharing
@@ -106,10 +137,21 @@ pub struct DeleteTextResponse {
     pub data: serde_json::Value,
 }
 
+impl DeleteTextResponse {
+    /// Synthetic response for a deletion that was not actually sent
+    pub(crate) fn dry_run() -> Self {
+        Self {
+            code: reqwest::StatusCode::OK.as_u16().into(),
+            message: DRY_RUN_MESSAGE.to_string(),
+            data: serde_json::Value::Null,
+        }
+    }
+}
+
 /// Data structure for text domains
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TextDomainsData {
-    pub domains: Vec<String>,
+    pub domains: Vec<DomainName>,
 }
 
 /// Response for getting text domains
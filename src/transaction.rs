@@ -0,0 +1,293 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: transaction.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-19 16:02:11
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 07:31:04
+ */
+
+//! Groups of create calls that are undone together on failure.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use see_sdk::client::Client;
+//! use see_sdk::config::Config;
+//! use see_sdk::transaction::Transaction;
+//! use see_sdk::url::builder::UrlShortenerRequestBuilder;
+//!
+//! let client = Client::new(Config::default().with_api_key("your-api-key")).unwrap();
+//!
+//! let links = Transaction::run(&client, |tx| {
+//!     let docs = tx.shorten(UrlShortenerRequestBuilder::new("https://example.com/docs")?.build())?;
+//!     let blog = tx.shorten(UrlShortenerRequestBuilder::new("https://example.com/blog")?.build())?;
+//!     Ok(vec![docs.data.short_url, blog.data.short_url])
+//! });
+//!
+//! if let Err(e) = links {
+//!     for (resource, error) in &e.rollback.failed {
+//!         eprintln!("left behind {:?}: {}", resource, error);
+//!     }
+//! }
+//! ```
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::file::FileService;
+use crate::file::models::FileUploadResponse;
+use crate::ledger::CreatedResource;
+use crate::text::TextService;
+use crate::text::handle::domain_from_short_url;
+use crate::text::models::{CreateTextRequest, CreateTextResponse};
+use crate::url::ShortenService;
+use crate::url::models::{ShortenRequest, ShortenResponse};
+use std::fmt;
+use std::path::Path;
+
+/// Outcome of rolling back a transaction
+#[derive(Debug, Default)]
+pub struct RollbackReport {
+    /// Resources that were deleted
+    pub deleted: Vec<CreatedResource>,
+
+    /// Resources that could not be deleted, with the reason
+    pub failed: Vec<(CreatedResource, Error)>,
+}
+
+impl RollbackReport {
+    /// Whether every created resource was deleted
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Error that aborted [`Transaction::run`], with the outcome of the rollback
+#[derive(Debug)]
+pub struct TransactionError {
+    /// The error returned by the transaction body
+    pub error: Error,
+
+    /// What the rollback deleted and what it left behind
+    pub rollback: RollbackReport,
+}
+
+impl fmt::Display for TransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+        if !self.rollback.is_complete() {
+            write!(
+                f,
+                " ({} created resource(s) could not be rolled back)",
+                self.rollback.failed.len()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for TransactionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<TransactionError> for Error {
+    fn from(e: TransactionError) -> Self {
+        e.error
+    }
+}
+
+/// Records every resource it creates so they can all be deleted again
+///
+/// A transaction that is dropped without [`commit`](Self::commit) or
+/// [`rollback`](Self::rollback) rolls back.
+#[derive(Debug)]
+pub struct Transaction {
+    client: Client,
+    created: Vec<CreatedResource>,
+    finished: bool,
+}
+
+impl Transaction {
+    /// Start a transaction on the given client
+    pub fn new(client: &Client) -> Self {
+        Self {
+            client: client.clone(),
+            created: Vec::new(),
+            finished: false,
+        }
+    }
+
+    /// Run `f` in a transaction, committing on success and rolling back
+    /// everything it created on error
+    ///
+    /// The error carries the [`RollbackReport`], so callers can tell
+    /// whether the created resources were actually deleted.
+    pub fn run<T, F>(client: &Client, f: F) -> std::result::Result<T, TransactionError>
+    where
+        F: FnOnce(&mut Transaction) -> Result<T>,
+    {
+        let mut tx = Transaction::new(client);
+
+        match f(&mut tx) {
+            Ok(value) => {
+                tx.commit();
+                Ok(value)
+            }
+            Err(error) => Err(TransactionError {
+                error,
+                rollback: tx.rollback(),
+            }),
+        }
+    }
+
+    /// Shorten a URL, recording the created link
+    pub fn shorten(&mut self, request: ShortenRequest) -> Result<ShortenResponse> {
+        let domain = request
+            .domain
+            .clone()
+            .unwrap_or_else(|| self.client.default_domain());
+        let response = self.client.shorten(request)?;
+
+        self.record(CreatedResource::Link {
+            domain,
+            slug: response.data.slug.clone(),
+        });
+        Ok(response)
+    }
+
+    /// Create a text sharing entry, recording it
+    pub fn create_text(&mut self, request: CreateTextRequest) -> Result<CreateTextResponse> {
+        let domain = request.domain.clone();
+        let response = self.client.create_text(request)?;
+
+        self.record(CreatedResource::Text {
+            domain: domain
+                .or_else(|| domain_from_short_url(&response.data.short_url))
+                .unwrap_or_default(),
+            slug: response.data.slug.clone(),
+        });
+        Ok(response)
+    }
+
+    /// Upload a file, recording it
+    pub fn upload_file<P: AsRef<Path>>(&mut self, file_path: P) -> Result<FileUploadResponse> {
+        let response = self.client.upload_file(file_path)?;
+
+        self.record(CreatedResource::File {
+            key: response.data.hash.clone(),
+        });
+        Ok(response)
+    }
+
+    /// Track a resource created outside the transaction
+    pub fn record(&mut self, resource: CreatedResource) {
+        self.created.push(resource);
+    }
+
+    /// Resources created so far, in creation order
+    pub fn created(&self) -> &[CreatedResource] {
+        &self.created
+    }
+
+    /// Keep everything that was created
+    pub fn commit(mut self) -> Vec<CreatedResource> {
+        self.finished = true;
+        std::mem::take(&mut self.created)
+    }
+
+    /// Delete everything that was created, newest first
+    pub fn rollback(mut self) -> RollbackReport {
+        self.finished = true;
+        self.rollback_created()
+    }
+
+    fn rollback_created(&mut self) -> RollbackReport {
+        let mut report = RollbackReport::default();
+
+        while let Some(resource) = self.created.pop() {
+            match resource.delete(&self.client) {
+                Ok(()) => report.deleted.push(resource),
+                Err(e) => {
+                    log::warn!("failed to roll back {:?}: {}", resource, e);
+                    report.failed.push((resource, e));
+                }
+            }
+        }
+
+        report
+    }
+}
+
+impl Drop for Transaction {
+    fn drop(&mut self) {
+        if !self.finished && !self.created.is_empty() {
+            log::warn!(
+                "transaction dropped without commit, rolling back {} resource(s)",
+                self.created.len()
+            );
+            self.rollback_created();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::types::FileKey;
+
+    /// Client pointing at a closed local port, so every request fails fast
+    fn unreachable_client() -> Client {
+        Client::new(Config::new("http://127.0.0.1:9").with_max_retries(0)).unwrap()
+    }
+
+    fn file_resource() -> CreatedResource {
+        CreatedResource::File {
+            key: FileKey::new("abc123").unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_commit_keeps_resources() {
+        let client = unreachable_client();
+        let mut tx = Transaction::new(&client);
+        tx.record(file_resource());
+
+        assert_eq!(tx.commit(), vec![file_resource()]);
+    }
+
+    #[test]
+    fn test_rollback_reports_failures() {
+        let client = unreachable_client();
+        let mut tx = Transaction::new(&client);
+        tx.record(file_resource());
+
+        let report = tx.rollback();
+        assert!(!report.is_complete());
+        assert!(report.deleted.is_empty());
+        assert_eq!(report.failed[0].0, file_resource());
+    }
+
+    #[test]
+    fn test_run_returns_error() {
+        let client = unreachable_client();
+
+        let result = Transaction::run(&client, |tx| -> Result<()> {
+            tx.record(file_resource());
+            Err(Error::GenericError("publish failed".to_string()))
+        });
+
+        let e = result.unwrap_err();
+        assert!(matches!(e.error, Error::GenericError(_)));
+        assert!(!e.rollback.is_complete());
+        assert_eq!(e.rollback.failed[0].0, file_resource());
+        assert!(e.to_string().contains("1 created resource(s)"));
+    }
+}
@@ -0,0 +1,422 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: types.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-19 10:15:32
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 08:20:33
+ */
+
+//! Strongly typed identifiers shared by the service models.
+//!
+//! Values built with `new` are validated locally. Values deserialized from
+//! API responses are taken as-is, since the service is authoritative for
+//! what it returns.
+
+use crate::config::DEFAULT_DOMAIN;
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use url::Host;
+use zeroize::Zeroize;
+
+/// Minimum length of a custom slug
+pub const SLUG_MIN_LEN: usize = 3;
+
+/// Maximum length of a custom slug
+pub const SLUG_MAX_LEN: usize = 64;
+
+/// Slug or file key used in synthetic dry-run responses when none was
+/// requested
+pub(crate) const DRY_RUN_SLUG: &str = "dry-run";
+
+/// Message of synthetic dry-run responses
+pub(crate) const DRY_RUN_MESSAGE: &str = "dry run";
+
+/// Implement the common string conversions for a newtype over `String`
+macro_rules! string_newtype {
+    ($name:ident) => {
+        impl $name {
+            /// Borrow the value as a string slice
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Consume the value, returning the inner string
+            pub fn into_inner(self) -> String {
+                self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self> {
+                Self::new(s)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+    };
+}
+
+/// The slug identifying a short link or text entry on its domain
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Slug(String);
+
+string_newtype!(Slug);
+
+impl Slug {
+    /// Create a slug identifying an existing link or text
+    ///
+    /// Only the shape of an identifier is checked: it must be non-empty and
+    /// contain no `/` or whitespace. Slugs chosen by the service need not
+    /// follow the custom slug rules of [`custom`](Self::custom).
+    pub fn new(slug: impl Into<String>) -> Result<Self> {
+        let slug = slug.into();
+
+        if slug.is_empty() {
+            return Err(Error::InvalidSlug("slug is empty".to_string()));
+        }
+
+        if let Some(c) = slug.chars().find(|c| *c == '/' || c.is_whitespace()) {
+            return Err(Error::InvalidSlug(format!(
+                "{:?} contains invalid character {:?}",
+                slug, c
+            )));
+        }
+
+        Ok(Self(slug))
+    }
+
+    /// Create a slug to request, validating it against the custom slug rules
+    ///
+    /// A custom slug is `SLUG_MIN_LEN` to `SLUG_MAX_LEN` ASCII letters,
+    /// digits, `-` or `_`, and must start and end with a letter or digit.
+    pub fn custom(slug: impl Into<String>) -> Result<Self> {
+        let slug = slug.into();
+        let len = slug.len();
+
+        if !(SLUG_MIN_LEN..=SLUG_MAX_LEN).contains(&len) {
+            return Err(Error::InvalidSlug(format!(
+                "{:?} must be {} to {} characters long",
+                slug, SLUG_MIN_LEN, SLUG_MAX_LEN
+            )));
+        }
+
+        if let Some(c) = slug
+            .chars()
+            .find(|c| !(c.is_ascii_alphanumeric() || *c == '-' || *c == '_'))
+        {
+            return Err(Error::InvalidSlug(format!(
+                "{:?} contains invalid character {:?}",
+                slug, c
+            )));
+        }
+
+        let is_edge_valid = |c: Option<char>| c.is_some_and(|c| c.is_ascii_alphanumeric());
+        if !is_edge_valid(slug.chars().next()) || !is_edge_valid(slug.chars().last()) {
+            return Err(Error::InvalidSlug(format!(
+                "{:?} must start and end with a letter or digit",
+                slug
+            )));
+        }
+
+        Ok(Self(slug))
+    }
+}
+
+/// A domain name, normalized to its lowercase ASCII (IDNA) form
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DomainName(String);
+
+string_newtype!(DomainName);
+
+impl DomainName {
+    /// Create a domain name, applying IDNA normalization
+    ///
+    /// Internationalized names are converted to punycode, so `Bücher.example`
+    /// becomes `xn--bcher-kva.example`. IP addresses are rejected.
+    pub fn new(domain: impl Into<String>) -> Result<Self> {
+        let domain = domain.into();
+        let trimmed = domain.trim().trim_end_matches('.');
+
+        if trimmed.is_empty() || trimmed.contains(['/', ':', '@']) {
+            return Err(Error::InvalidDomain(domain));
+        }
+
+        match Host::parse(trimmed) {
+            Ok(Host::Domain(normalized)) => Ok(Self(normalized)),
+            _ => Err(Error::InvalidDomain(domain)),
+        }
+    }
+}
+
+impl Default for DomainName {
+    fn default() -> Self {
+        Self(DEFAULT_DOMAIN.to_string())
+    }
+}
+
+/// The numeric identifier of a tag
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TagId(u32);
+
+impl TagId {
+    /// Create a tag ID
+    pub const fn new(id: u32) -> Self {
+        Self(id)
+    }
+
+    /// The raw numeric value
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+}
+
+impl From<u32> for TagId {
+    fn from(id: u32) -> Self {
+        Self(id)
+    }
+}
+
+impl From<TagId> for u32 {
+    fn from(id: TagId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for TagId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for TagId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        s.trim()
+            .parse()
+            .map(Self)
+            .map_err(|_| Error::GenericError(format!("Invalid tag ID: {:?}", s)))
+    }
+}
+
+/// The key (hash) identifying an uploaded file
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FileKey(String);
+
+string_newtype!(FileKey);
+
+impl FileKey {
+    /// Create a file key
+    ///
+    /// The key is used as a path segment, so it must be non-empty and only
+    /// contain ASCII letters, digits, `-` or `_`.
+    pub fn new(key: impl Into<String>) -> Result<Self> {
+        let key = key.into();
+
+        if key.is_empty()
+            || !key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(Error::InvalidFileKey(key));
+        }
+
+        Ok(Self(key))
+    }
+}
+
+/// A secret such as an API key or password
+///
+/// `Debug` and `Display` print `***`, and the value is zeroized on drop.
+/// Serialization sends the real value, since it is only serialized into
+/// request bodies.
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    /// Wrap a secret value
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self(secret.into())
+    }
+
+    /// The secret value; keep it out of logs
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("***")
+    }
+}
+
+impl fmt::Display for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("***")
+    }
+}
+
+impl Serialize for Secret {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl From<String> for Secret {
+    fn from(secret: String) -> Self {
+        Self(secret)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(secret: &str) -> Self {
+        Self(secret.to_string())
+    }
+}
+
+impl From<&String> for Secret {
+    fn from(secret: &String) -> Self {
+        Self(secret.clone())
+    }
+}
+
+impl PartialEq<str> for Secret {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Secret {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slug_validation() {
+        assert!(Slug::custom("summer-sale").is_ok());
+        assert!(Slug::custom("abc_123").is_ok());
+
+        assert!(Slug::custom("ab").is_err());
+        assert!(Slug::custom("a".repeat(SLUG_MAX_LEN + 1)).is_err());
+        assert!(Slug::custom("with space").is_err());
+        assert!(Slug::custom("-leading").is_err());
+        assert!(Slug::custom("trailing_").is_err());
+        assert!(Slug::custom("ünicode").is_err());
+    }
+
+    #[test]
+    fn test_slug_identifiers_are_permissive() {
+        // Slugs the service generated may break the custom slug rules
+        assert!(Slug::new("ab").is_ok());
+        assert!(Slug::new("-leading").is_ok());
+        assert!(Slug::new("ünicode").is_ok());
+        assert!(Slug::new("a".repeat(SLUG_MAX_LEN + 1)).is_ok());
+
+        assert!(Slug::new("").is_err());
+        assert!(Slug::new("with space").is_err());
+        assert!(Slug::new("a/b").is_err());
+    }
+
+    #[test]
+    fn test_domain_normalization() {
+        assert_eq!(DomainName::new("S.EE").unwrap(), "s.ee");
+        assert_eq!(DomainName::new("s.ee.").unwrap(), "s.ee");
+        assert_eq!(
+            DomainName::new("Bücher.example").unwrap(),
+            "xn--bcher-kva.example"
+        );
+        assert_eq!(DomainName::default(), DEFAULT_DOMAIN);
+
+        assert!(DomainName::new("").is_err());
+        assert!(DomainName::new("127.0.0.1").is_err());
+        assert!(DomainName::new("s.ee/path").is_err());
+        assert!(DomainName::new("s.ee:8080").is_err());
+    }
+
+    #[test]
+    fn test_file_key_and_tag_id() {
+        assert!(FileKey::new("aBc123").is_ok());
+        assert!(FileKey::new("").is_err());
+        assert!(FileKey::new("../etc").is_err());
+
+        assert_eq!("42".parse::<TagId>().unwrap(), TagId::new(42));
+        assert!("tag".parse::<TagId>().is_err());
+    }
+
+    #[test]
+    fn test_transparent_serialization() {
+        let slug = Slug::new("summer-sale").unwrap();
+        assert_eq!(serde_json::to_string(&slug).unwrap(), "\"summer-sale\"");
+
+        let id: TagId = serde_json::from_str("7").unwrap();
+        assert_eq!(id.get(), 7);
+    }
+
+    #[test]
+    fn test_secret_is_redacted() {
+        let secret = Secret::new("hunter2");
+
+        assert_eq!(format!("{:?}", secret), "***");
+        assert_eq!(secret.to_string(), "***");
+        assert_eq!(secret.expose(), "hunter2");
+        assert_eq!(serde_json::to_string(&secret).unwrap(), "\"hunter2\"");
+    }
+}
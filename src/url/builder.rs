@@ -8,19 +8,26 @@
  * Author: mingcheng <mingcheng@apache.org>
  * File Created: 2025-10-23 11:30:17
  *
- * Modified By: mingcheng <mingcheng@apache.org>
- * Last Modified: 2025-10-23 17:31:41
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 08:20:33
  */
 
 use std::error::Error;
+use std::sync::Arc;
 
 use url::Url;
 
-use crate::url::models::ShortenRequest;
+use crate::error::Result as SeeResult;
+use crate::types::{DomainName, Slug};
+use crate::url::ShortenService;
+use crate::url::generator::{DEFAULT_MAX_SLUG_ATTEMPTS, SlugGenerator};
+use crate::url::models::{ShortenRequest, ShortenResponse};
 
 /// Builder for creating a URL shortening request
 pub struct UrlShortenerRequestBuilder {
     data: ShortenRequest,
+    slug_generator: Option<Arc<dyn SlugGenerator>>,
+    max_slug_attempts: u32,
 }
 
 impl UrlShortenerRequestBuilder {
@@ -41,12 +48,39 @@ impl UrlShortenerRequestBuilder {
                 target_url: url.into(),
                 ..Default::default()
             },
+            slug_generator: None,
+            max_slug_attempts: DEFAULT_MAX_SLUG_ATTEMPTS,
         })
     }
 
     /// Set a custom alias (slug) for the short URL
-    pub fn with_custom_alias(mut self, alias: impl Into<String>) -> Self {
-        self.data.custom_slug = Some(alias.into());
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidSlug`](crate::error::Error::InvalidSlug) if the
+    /// alias doesn't follow the custom slug rules, see [`Slug::custom`]
+    pub fn with_custom_alias(self, alias: impl Into<String>) -> SeeResult<Self> {
+        Ok(self.with_slug(Slug::custom(alias)?))
+    }
+
+    /// Set an already validated slug for the short URL
+    pub fn with_slug(mut self, slug: Slug) -> Self {
+        self.data.custom_slug = Some(slug);
+        self
+    }
+
+    /// Generate the slug with the given strategy when the request is sent
+    ///
+    /// Only used by [`send`](Self::send), which retries with the next
+    /// candidate when a generated slug is already taken.
+    pub fn with_slug_generator(mut self, generator: Arc<dyn SlugGenerator>) -> Self {
+        self.slug_generator = Some(generator);
+        self
+    }
+
+    /// Set how many generated slugs are tried before giving up
+    pub fn with_max_slug_attempts(mut self, attempts: u32) -> Self {
+        self.max_slug_attempts = attempts.max(1);
         self
     }
 
@@ -57,8 +91,18 @@ impl UrlShortenerRequestBuilder {
     }
 
     /// Set the domain for the short URL
-    pub fn with_domain(mut self, domain: impl Into<String>) -> Self {
-        self.data.domain = domain.into();
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidDomain`](crate::error::Error::InvalidDomain)
+    /// if the domain isn't a valid host name, see [`DomainName::new`]
+    pub fn with_domain(self, domain: impl Into<String>) -> SeeResult<Self> {
+        Ok(self.with_domain_name(DomainName::new(domain)?))
+    }
+
+    /// Set an already validated domain for the short URL
+    pub fn with_domain_name(mut self, domain: DomainName) -> Self {
+        self.data.domain = Some(domain);
         self
     }
 
@@ -66,11 +110,29 @@ impl UrlShortenerRequestBuilder {
     pub fn build(self) -> ShortenRequest {
         self.data
     }
+
+    /// Build the request and shorten it with the given service, using the
+    /// slug generator if one is set
+    pub fn send<S: ShortenService>(self, service: &S) -> SeeResult<ShortenResponse> {
+        match self.slug_generator {
+            Some(generator) => service.shorten_with_generator(
+                self.data,
+                generator.as_ref(),
+                self.max_slug_attempts,
+            ),
+            None => service.shorten(self.data),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::url::handle::LinkHandle;
+    use crate::url::models::{
+        DeleteRequest, DeleteResponse, GetAvailableDomainsResponse, GetLinkVisitStatRequest,
+        GetLinkVisitStatResponse, ShortenData, UpdateShortURLRequest,
+    };
 
     #[test]
     fn test_builder_with_basic_url() {
@@ -87,6 +149,7 @@ mod tests {
         let request = UrlShortenerRequestBuilder::new("https://example.com/")
             .unwrap()
             .with_custom_alias("my-alias")
+            .unwrap()
             .build();
 
         assert_eq!(request.target_url, "https://example.com/");
@@ -98,16 +161,135 @@ mod tests {
         let request = UrlShortenerRequestBuilder::new("https://example.com/")
             .unwrap()
             .with_custom_alias("my-alias")
+            .unwrap()
             .with_domain("custom.domain")
+            .unwrap()
             .with_expiration(1735689600)
             .build();
 
         assert_eq!(request.target_url, "https://example.com/");
         assert_eq!(request.custom_slug.unwrap(), "my-alias");
-        assert_eq!(request.domain, "custom.domain");
+        assert_eq!(request.domain.unwrap(), "custom.domain");
         assert_eq!(request.expire_at.unwrap(), 1735689600);
     }
 
+    #[test]
+    fn test_builder_rejects_invalid_alias() {
+        let builder = UrlShortenerRequestBuilder::new("https://example.com/").unwrap();
+        let result = builder.with_custom_alias("not valid!");
+        assert!(matches!(result, Err(crate::error::Error::InvalidSlug(_))));
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_domain() {
+        let builder = UrlShortenerRequestBuilder::new("https://example.com/").unwrap();
+        let result = builder.with_domain("not a domain");
+        assert!(matches!(result, Err(crate::error::Error::InvalidDomain(_))));
+    }
+
+    /// Fake service that reports the first `taken` slugs as conflicts and
+    /// supports nothing but shortening
+    struct ConflictingService {
+        taken: u32,
+        calls: std::cell::Cell<u32>,
+    }
+
+    fn unsupported<T>() -> SeeResult<T> {
+        Err(crate::error::Error::GenericError(
+            "not supported by the fake service".to_string(),
+        ))
+    }
+
+    impl ShortenService for ConflictingService {
+        fn shorten(&self, request: ShortenRequest) -> SeeResult<ShortenResponse> {
+            let call = self.calls.get();
+            self.calls.set(call + 1);
+
+            if call < self.taken {
+                return Err(crate::error::Error::ServerError {
+                    status: 409,
+                    message: "slug already exists".to_string(),
+                });
+            }
+
+            let slug = request.custom_slug.unwrap();
+            Ok(ShortenResponse {
+                code: 200,
+                message: "success".to_string(),
+                data: ShortenData {
+                    custom_slug: Some(slug.clone()),
+                    short_url: format!("https://{}/{}", request.domain.unwrap_or_default(), slug),
+                    slug,
+                },
+            })
+        }
+
+        fn shorten_handle(&self, _: ShortenRequest) -> SeeResult<LinkHandle> {
+            unsupported()
+        }
+
+        fn update_short_url(&self, _: UpdateShortURLRequest) -> SeeResult<ShortenResponse> {
+            unsupported()
+        }
+
+        fn delete(&self, _: DeleteRequest) -> SeeResult<DeleteResponse> {
+            unsupported()
+        }
+
+        fn get_link_visit_stat(
+            &self,
+            _: GetLinkVisitStatRequest,
+        ) -> SeeResult<GetLinkVisitStatResponse> {
+            unsupported()
+        }
+
+        fn get_available_domains(&self) -> SeeResult<GetAvailableDomainsResponse> {
+            unsupported()
+        }
+
+        fn is_slug_available(&self, _: &DomainName, _: &Slug) -> SeeResult<bool> {
+            unsupported()
+        }
+    }
+
+    #[test]
+    fn test_send_retries_taken_generated_slugs() {
+        use crate::url::generator::CounterGenerator;
+
+        let service = ConflictingService {
+            taken: 2,
+            calls: Default::default(),
+        };
+
+        let response = UrlShortenerRequestBuilder::new("https://example.com/")
+            .unwrap()
+            .with_slug_generator(Arc::new(CounterGenerator::new("promo", 1)))
+            .send(&service)
+            .unwrap();
+
+        assert_eq!(response.data.slug, "promo3");
+        assert_eq!(service.calls.get(), 3);
+    }
+
+    #[test]
+    fn test_send_gives_up_after_max_attempts() {
+        use crate::url::generator::CounterGenerator;
+
+        let service = ConflictingService {
+            taken: 10,
+            calls: Default::default(),
+        };
+
+        let result = UrlShortenerRequestBuilder::new("https://example.com/")
+            .unwrap()
+            .with_slug_generator(Arc::new(CounterGenerator::new("promo", 1)))
+            .with_max_slug_attempts(2)
+            .send(&service);
+
+        assert!(result.unwrap_err().is_conflict());
+        assert_eq!(service.calls.get(), 2);
+    }
+
     #[test]
     fn test_builder_with_invalid_url() {
         let result = UrlShortenerRequestBuilder::new("not-a-valid-url");
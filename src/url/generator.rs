@@ -0,0 +1,287 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: generator.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-19 12:03:48
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 08:29:47
+ */
+
+//! Strategies for generating custom slugs on the client side.
+
+use crate::error::Result;
+use crate::types::Slug;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Default number of candidates tried before giving up on a taken slug
+pub const DEFAULT_MAX_SLUG_ATTEMPTS: u32 = 5;
+
+/// Produces candidate slugs for a target URL
+///
+/// `attempt` starts at 0 and increases each time the previous candidate was
+/// already taken, so generators must return a different slug per attempt.
+pub trait SlugGenerator: Send + Sync {
+    /// Generate the candidate slug for the given attempt
+    fn generate(&self, target_url: &str, attempt: u32) -> Result<Slug>;
+}
+
+const ADJECTIVES: &[&str] = &[
+    "amber", "bold", "brave", "bright", "calm", "clever", "cosmic", "crisp", "daring", "eager",
+    "fancy", "gentle", "golden", "happy", "jolly", "keen", "lively", "lucky", "merry", "mighty",
+    "noble", "proud", "quick", "quiet", "rapid", "rosy", "shiny", "silent", "sunny", "swift",
+    "tidy", "vivid", "witty", "zesty",
+];
+
+const NOUNS: &[&str] = &[
+    "anchor", "badger", "beacon", "canyon", "comet", "coral", "falcon", "forest", "galaxy",
+    "harbor", "island", "lagoon", "lantern", "maple", "meadow", "meteor", "nebula", "orchid",
+    "otter", "panda", "pepper", "pine", "planet", "river", "rocket", "sparrow", "summit", "tiger",
+    "tulip", "valley", "willow", "zebra",
+];
+
+/// Memorable `adjective-noun-NN` slugs picked from word lists
+pub struct WordlistGenerator {
+    adjectives: Vec<String>,
+    nouns: Vec<String>,
+    seed: Option<u64>,
+}
+
+impl Default for WordlistGenerator {
+    fn default() -> Self {
+        Self::new(
+            ADJECTIVES.iter().map(|w| w.to_string()).collect(),
+            NOUNS.iter().map(|w| w.to_string()).collect(),
+        )
+    }
+}
+
+impl WordlistGenerator {
+    /// Create a generator from custom word lists
+    ///
+    /// Words should only contain characters allowed in slugs.
+    pub fn new(adjectives: Vec<String>, nouns: Vec<String>) -> Self {
+        Self {
+            adjectives,
+            nouns,
+            seed: None,
+        }
+    }
+
+    /// Use a fixed seed, making the generated slugs reproducible
+    ///
+    /// Seeded slugs are hashed with FNV-1a, so they stay the same across
+    /// platforms and Rust releases.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    fn random(&self, target_url: &str, attempt: u32) -> u64 {
+        match self.seed {
+            Some(seed) => {
+                let mut input = seed.to_le_bytes().to_vec();
+                input.extend_from_slice(&attempt.to_le_bytes());
+                input.extend_from_slice(target_url.as_bytes());
+                fnv1a(&input)
+            }
+            None => {
+                let nanos = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_nanos())
+                    .unwrap_or_default();
+                RandomState::new().hash_one((target_url, attempt, nanos))
+            }
+        }
+    }
+}
+
+impl SlugGenerator for WordlistGenerator {
+    fn generate(&self, target_url: &str, attempt: u32) -> Result<Slug> {
+        let random = self.random(target_url, attempt);
+        let pick = |words: &[String], n: u64| -> String {
+            if words.is_empty() {
+                String::new()
+            } else {
+                words[(n % words.len() as u64) as usize].clone()
+            }
+        };
+
+        let adjective = pick(&self.adjectives, random);
+        let noun = pick(&self.nouns, random >> 16);
+        let number = (random >> 32) % 100;
+
+        Slug::custom(format!("{}-{}-{:02}", adjective, noun, number))
+    }
+}
+
+/// Deterministic base62 slugs derived from a hash of the target URL and a salt
+///
+/// The same target and salt always produce the same first candidate, which
+/// makes re-running a publishing job stable.
+pub struct HashGenerator {
+    salt: String,
+    length: usize,
+}
+
+impl HashGenerator {
+    /// Shortest supported slug length
+    pub const MIN_LENGTH: usize = 3;
+
+    /// Longest supported slug length, the base62 width of a 64-bit hash
+    pub const MAX_LENGTH: usize = 11;
+
+    /// Create a generator with the given salt and a 7 character slug length
+    pub fn new(salt: impl Into<String>) -> Self {
+        Self {
+            salt: salt.into(),
+            length: 7,
+        }
+    }
+
+    /// Set the slug length, clamped to `MIN_LENGTH..=MAX_LENGTH`
+    pub fn with_length(mut self, length: usize) -> Self {
+        self.length = length.clamp(Self::MIN_LENGTH, Self::MAX_LENGTH);
+        self
+    }
+}
+
+impl SlugGenerator for HashGenerator {
+    fn generate(&self, target_url: &str, attempt: u32) -> Result<Slug> {
+        let input = if attempt == 0 {
+            format!("{}{}", self.salt, target_url)
+        } else {
+            format!("{}{}#{}", self.salt, target_url, attempt)
+        };
+
+        let mut encoded = base62(fnv1a(input.as_bytes()));
+        encoded.truncate(self.length);
+
+        Slug::custom(encoded)
+    }
+}
+
+/// Sequential `prefix` + counter slugs, such as `spring-0001`
+pub struct CounterGenerator {
+    prefix: String,
+    width: usize,
+    next: AtomicU64,
+}
+
+impl CounterGenerator {
+    /// Create a generator starting the counter at `start`
+    pub fn new(prefix: impl Into<String>, start: u64) -> Self {
+        Self {
+            prefix: prefix.into(),
+            width: 0,
+            next: AtomicU64::new(start),
+        }
+    }
+
+    /// Zero-pad the counter to the given width
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+}
+
+impl SlugGenerator for CounterGenerator {
+    /// Each call consumes the next counter value, so a taken slug is skipped
+    fn generate(&self, _target_url: &str, _attempt: u32) -> Result<Slug> {
+        let n = self.next.fetch_add(1, Ordering::Relaxed);
+        Slug::custom(format!("{}{:0width$}", self.prefix, n, width = self.width))
+    }
+}
+
+/// 64-bit FNV-1a, stable across platforms and compiler versions
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Encode a number in base62, padded to `HashGenerator::MAX_LENGTH` digits
+fn base62(mut n: u64) -> String {
+    const ALPHABET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+    let mut digits = Vec::with_capacity(HashGenerator::MAX_LENGTH);
+    for _ in 0..HashGenerator::MAX_LENGTH {
+        digits.push(ALPHABET[(n % 62) as usize]);
+        n /= 62;
+    }
+
+    String::from_utf8(digits).expect("base62 alphabet is ASCII")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wordlist_generator() {
+        let generator = WordlistGenerator::default().with_seed(42);
+        let first = generator.generate("https://example.com/", 0).unwrap();
+        let again = generator.generate("https://example.com/", 0).unwrap();
+        let retry = generator.generate("https://example.com/", 1).unwrap();
+
+        assert_eq!(first, again);
+        assert_ne!(first, retry);
+        assert_eq!(first.as_str().split('-').count(), 3);
+    }
+
+    #[test]
+    fn test_wordlist_generator_seed_is_stable() {
+        let generator = WordlistGenerator::default().with_seed(42);
+
+        assert_eq!(
+            generator.generate("https://example.com/", 0).unwrap(),
+            "daring-tiger-48"
+        );
+        assert_eq!(
+            generator.generate("https://example.com/", 1).unwrap(),
+            "eager-tiger-06"
+        );
+    }
+
+    #[test]
+    fn test_hash_generator_is_deterministic() {
+        let generator = HashGenerator::new("salt").with_length(8);
+        let first = generator.generate("https://example.com/", 0).unwrap();
+
+        assert_eq!(first.as_str().len(), 8);
+        assert_eq!(
+            first,
+            HashGenerator::new("salt")
+                .with_length(8)
+                .generate("https://example.com/", 0)
+                .unwrap()
+        );
+        assert_ne!(
+            first,
+            generator.generate("https://example.com/", 1).unwrap()
+        );
+        assert_ne!(
+            first,
+            HashGenerator::new("pepper")
+                .with_length(8)
+                .generate("https://example.com/", 0)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_counter_generator() {
+        let generator = CounterGenerator::new("spring-", 9).with_width(3);
+
+        assert_eq!(generator.generate("", 0).unwrap(), "spring-009");
+        assert_eq!(generator.generate("", 1).unwrap(), "spring-010");
+        assert!(CounterGenerator::new("-bad", 1).generate("", 0).is_err());
+    }
+}
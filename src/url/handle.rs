@@ -0,0 +1,163 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: handle.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-19 09:12:40
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-19 11:06:12
+ */
+
+use crate::client::Client;
+use crate::error::Result;
+use crate::types::{DomainName, Slug};
+use crate::url::ShortenService;
+use crate::url::models::{
+    DeleteRequest, DeleteResponse, GetLinkVisitStatRequest, GetLinkVisitStatResponse, ShortenData,
+    ShortenResponse, UpdateShortURLRequest,
+};
+
+/// A short link bound to the client that manages it
+///
+/// Returned by [`ShortenService::shorten_handle`], or attached to an existing
+/// link with [`LinkHandle::new`]. Follow-up operations reuse the link's domain
+/// and slug, so they can't be mixed up with another resource.
+#[derive(Debug, Clone)]
+pub struct LinkHandle {
+    client: Client,
+    domain: DomainName,
+    slug: Slug,
+    short_url: String,
+}
+
+impl LinkHandle {
+    /// Attach a handle to an existing short link
+    pub fn new(client: Client, domain: DomainName, slug: Slug) -> Self {
+        let short_url = format!("https://{}/{}", domain, slug);
+
+        Self {
+            client,
+            domain,
+            slug,
+            short_url,
+        }
+    }
+
+    /// Create a handle from the data returned by a shorten call
+    pub(crate) fn from_data(client: Client, domain: DomainName, data: ShortenData) -> Self {
+        Self {
+            client,
+            domain,
+            slug: data.slug,
+            short_url: data.short_url,
+        }
+    }
+
+    /// The domain of the short link
+    pub fn domain(&self) -> &DomainName {
+        &self.domain
+    }
+
+    /// The slug of the short link
+    pub fn slug(&self) -> &Slug {
+        &self.slug
+    }
+
+    /// The full short URL
+    pub fn short_url(&self) -> &str {
+        &self.short_url
+    }
+
+    /// Start an update pointing the link at a new target URL
+    pub fn update(&self, target_url: impl Into<String>) -> LinkUpdate<'_> {
+        LinkUpdate {
+            handle: self,
+            target_url: target_url.into(),
+            title: None,
+        }
+    }
+
+    /// Get visit statistics for the link
+    pub fn stats(&self) -> Result<GetLinkVisitStatResponse> {
+        self.stats_request(None)
+    }
+
+    /// Get visit statistics for the link over the given period
+    pub fn stats_for_period(&self, period: impl Into<String>) -> Result<GetLinkVisitStatResponse> {
+        self.stats_request(Some(period.into()))
+    }
+
+    fn stats_request(&self, period: Option<String>) -> Result<GetLinkVisitStatResponse> {
+        self.client.get_link_visit_stat(GetLinkVisitStatRequest {
+            domain: self.domain.clone(),
+            slug: self.slug.clone(),
+            period,
+        })
+    }
+
+    /// Delete the link, consuming the handle
+    pub fn delete(self) -> Result<DeleteResponse> {
+        self.client.delete(DeleteRequest {
+            domain: self.domain,
+            slug: self.slug,
+        })
+    }
+}
+
+/// Pending update of a short link, created by [`LinkHandle::update`]
+pub struct LinkUpdate<'a> {
+    handle: &'a LinkHandle,
+    target_url: String,
+    title: Option<String>,
+}
+
+impl LinkUpdate<'_> {
+    /// Set a new title for the link
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Send the update to the service
+    pub fn send(self) -> Result<ShortenResponse> {
+        self.handle.client.update_short_url(UpdateShortURLRequest {
+            domain: self.handle.domain.clone(),
+            slug: self.handle.slug.clone(),
+            target_url: self.target_url,
+            title: self.title,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DEFAULT_DOMAIN;
+
+    fn test_link() -> LinkHandle {
+        let client = Client::with_default_config().unwrap();
+        LinkHandle::new(client, DomainName::default(), Slug::new("abc123").unwrap())
+    }
+
+    #[test]
+    fn test_attach_to_existing_link() {
+        let link = test_link();
+
+        assert_eq!(link.domain(), DEFAULT_DOMAIN);
+        assert_eq!(link.slug(), "abc123");
+        assert_eq!(link.short_url(), "https://s.ee/abc123");
+    }
+
+    #[test]
+    fn test_update_builder() {
+        let link = test_link();
+
+        let update = link.update("https://example.com/").with_title("Example");
+        assert_eq!(update.target_url, "https://example.com/");
+        assert_eq!(update.title.as_deref(), Some("Example"));
+    }
+}
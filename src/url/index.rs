@@ -0,0 +1,242 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: index.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-19 13:31:14
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 07:31:04
+ */
+
+//! Local index of created short links, used for get-or-create shortening.
+
+use crate::error::Result;
+use crate::types::{DomainName, Slug};
+use crate::url::models::{ShortenData, ShortenRequest};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+/// The request fields that identify an existing link
+///
+/// The domain is always part of the key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MatchKey {
+    /// Match on the target URL only
+    #[default]
+    Target,
+
+    /// Match on the target URL and the set of tag IDs
+    TargetAndTags,
+
+    /// Match on the target URL and the title
+    TargetAndTitle,
+}
+
+impl MatchKey {
+    /// Compute the index key of a request
+    pub fn key_for(&self, request: &ShortenRequest) -> String {
+        let domain = request.domain.as_ref().map_or("", DomainName::as_str);
+        let mut key = format!("{}\n{}", domain, request.target_url);
+
+        match self {
+            MatchKey::Target => {}
+            MatchKey::TargetAndTags => {
+                let mut tags: Vec<u32> = request
+                    .tag_ids
+                    .iter()
+                    .flatten()
+                    .map(|id| id.get())
+                    .collect();
+                tags.sort_unstable();
+                tags.dedup();
+
+                let tags: Vec<String> = tags.iter().map(u32::to_string).collect();
+                key.push_str("\ntags:");
+                key.push_str(&tags.join(","));
+            }
+            MatchKey::TargetAndTitle => {
+                key.push_str("\ntitle:");
+                key.push_str(request.title.as_deref().unwrap_or_default());
+            }
+        }
+
+        key
+    }
+}
+
+/// A short link recorded in the index
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct IndexedLink {
+    /// The domain of the short link
+    pub domain: DomainName,
+
+    /// The slug of the short link
+    pub slug: Slug,
+
+    /// The full short URL
+    pub short_url: String,
+
+    /// The custom slug, if one was requested
+    pub custom_slug: Option<Slug>,
+}
+
+impl From<IndexedLink> for ShortenData {
+    fn from(link: IndexedLink) -> Self {
+        Self {
+            custom_slug: link.custom_slug,
+            short_url: link.short_url,
+            slug: link.slug,
+        }
+    }
+}
+
+/// Index of short links by match key, optionally persisted to a JSON file
+#[derive(Debug, Default)]
+pub struct LinkIndex {
+    links: Mutex<HashMap<String, IndexedLink>>,
+    path: Option<PathBuf>,
+}
+
+impl LinkIndex {
+    /// Create an empty in-memory index
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open an index persisted at `path`, creating it on the first insert
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+
+        let links = if path.exists() {
+            serde_json::from_str(&fs::read_to_string(&path)?)?
+        } else {
+            HashMap::new()
+        };
+
+        Ok(Self {
+            links: Mutex::new(links),
+            path: Some(path),
+        })
+    }
+
+    /// Look up the link recorded under a key
+    pub fn get(&self, key: &str) -> Option<IndexedLink> {
+        self.lock().get(key).cloned()
+    }
+
+    /// Record a link under a key
+    pub fn insert(&self, key: impl Into<String>, link: IndexedLink) -> Result<()> {
+        let mut links = self.lock();
+        links.insert(key.into(), link);
+        self.persist(&links)
+    }
+
+    /// Forget the link recorded under a key
+    pub fn remove(&self, key: &str) -> Result<Option<IndexedLink>> {
+        let mut links = self.lock();
+        let removed = links.remove(key);
+        self.persist(&links)?;
+        Ok(removed)
+    }
+
+    /// Number of indexed links
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether the index is empty
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, IndexedLink>> {
+        self.links.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Write the index to its file, replacing it atomically
+    fn persist(&self, links: &HashMap<String, IndexedLink>) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, serde_json::to_vec_pretty(links)?)?;
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TagId;
+    use std::env;
+
+    fn request(title: Option<&str>, tags: Option<Vec<u32>>) -> ShortenRequest {
+        ShortenRequest {
+            target_url: "https://example.com/".to_string(),
+            title: title.map(str::to_string),
+            tag_ids: tags.map(|tags| tags.into_iter().map(TagId::new).collect()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_match_keys() {
+        let plain = request(None, None);
+        let titled = request(Some("Launch"), Some(vec![2, 1]));
+        let reordered = request(Some("Other"), Some(vec![1, 2, 2]));
+
+        assert_eq!(
+            MatchKey::Target.key_for(&plain),
+            MatchKey::Target.key_for(&titled)
+        );
+        assert_eq!(
+            MatchKey::TargetAndTags.key_for(&titled),
+            MatchKey::TargetAndTags.key_for(&reordered)
+        );
+        assert_ne!(
+            MatchKey::TargetAndTags.key_for(&plain),
+            MatchKey::TargetAndTags.key_for(&titled)
+        );
+        assert_ne!(
+            MatchKey::TargetAndTitle.key_for(&titled),
+            MatchKey::TargetAndTitle.key_for(&reordered)
+        );
+    }
+
+    #[test]
+    fn test_index_persistence() -> Result<()> {
+        let mut path = env::temp_dir();
+        path.push(format!("see-sdk-link-index-{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let link = IndexedLink {
+            domain: DomainName::default(),
+            slug: Slug::new("abc123")?,
+            short_url: "https://s.ee/abc123".to_string(),
+            custom_slug: None,
+        };
+
+        let index = LinkIndex::open(&path)?;
+        index.insert("key", link.clone())?;
+
+        let reopened = LinkIndex::open(&path)?;
+        assert_eq!(reopened.get("key"), Some(link));
+        assert_eq!(
+            reopened.remove("key")?.map(|l| l.slug),
+            Some(Slug::new("abc123")?)
+        );
+        assert!(LinkIndex::open(&path)?.is_empty());
+
+        let _ = fs::remove_file(path);
+        Ok(())
+    }
+}
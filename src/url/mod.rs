@@ -8,24 +8,123 @@
  * Author: mingcheng <mingcheng@apache.org>
  * File Created: 2025-10-23 17:27:21
  *
- * Modified By: mingcheng <mingcheng@apache.org>
- * Last Modified: 2025-10-23 22:52:49
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 08:41:12
  */
 
 use crate::client::Client;
 use crate::error::{Error, Result};
+use crate::ledger::{CreatedResource, LedgerAction, LedgerEntry};
+use crate::types::{DomainName, Slug};
+use crate::url::generator::SlugGenerator;
+use crate::url::handle::LinkHandle;
+use crate::url::index::{IndexedLink, LinkIndex, MatchKey};
 use crate::url::models::{
     DeleteRequest, DeleteResponse, GetAvailableDomainsResponse, GetLinkVisitStatRequest,
     GetLinkVisitStatResponse, ShortenRequest, ShortenResponse, UpdateShortURLRequest,
 };
 
 pub mod builder;
+pub mod generator;
+pub mod handle;
+pub mod index;
 pub mod models;
 
 pub trait ShortenService {
+    /// Domain used by requests that don't name one
+    fn default_domain(&self) -> DomainName {
+        DomainName::default()
+    }
+
     /// Shorten a new URL
     fn shorten(&self, request: ShortenRequest) -> Result<ShortenResponse>;
 
+    /// Shorten a new URL and return a handle bound to the created link
+    fn shorten_handle(&self, request: ShortenRequest) -> Result<LinkHandle>;
+
+    /// Shorten a new URL with a slug from the generator, moving on to the
+    /// next candidate while the server reports the slug as taken
+    fn shorten_with_generator(
+        &self,
+        mut request: ShortenRequest,
+        generator: &dyn SlugGenerator,
+        max_attempts: u32,
+    ) -> Result<ShortenResponse> {
+        let mut attempt = 0;
+
+        loop {
+            request.custom_slug = Some(generator.generate(&request.target_url, attempt)?);
+
+            match self.shorten(request.clone()) {
+                Err(e) if e.is_conflict() && attempt + 1 < max_attempts => {
+                    log::debug!(
+                        "slug {:?} is taken, trying next candidate",
+                        request.custom_slug
+                    );
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Return the link already created for an equivalent request, or shorten
+    /// the URL and record the new link in the index
+    ///
+    /// Indexed links are checked against the server before being reused, so
+    /// a link deleted elsewhere is created again. Dry-run responses are
+    /// never indexed.
+    fn shorten_idempotent(
+        &self,
+        mut request: ShortenRequest,
+        index: &LinkIndex,
+        match_key: MatchKey,
+    ) -> Result<ShortenResponse> {
+        let domain = request
+            .domain
+            .get_or_insert_with(|| self.default_domain())
+            .clone();
+        let key = match_key.key_for(&request);
+
+        if let Some(link) = index.get(&key) {
+            match self.get_link_visit_stat(GetLinkVisitStatRequest {
+                domain: link.domain.clone(),
+                slug: link.slug.clone(),
+                period: None,
+            }) {
+                Ok(_) => {
+                    return Ok(ShortenResponse {
+                        code: reqwest::StatusCode::OK.as_u16(),
+                        message: "existing link".to_string(),
+                        data: link.into(),
+                    });
+                }
+                Err(Error::NotFound) | Err(Error::ServerError { status: 404, .. }) => {
+                    log::debug!("indexed link {} no longer exists", link.short_url);
+                    index.remove(&key)?;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        let response = self.shorten(request)?;
+        if response.is_dry_run() {
+            return Ok(response);
+        }
+
+        index.insert(
+            key,
+            IndexedLink {
+                domain,
+                slug: response.data.slug.clone(),
+                short_url: response.data.short_url.clone(),
+                custom_slug: response.data.custom_slug.clone(),
+            },
+        )?;
+
+        Ok(response)
+    }
+
     /// Update an existing short URL
     fn update_short_url(&self, request: UpdateShortURLRequest) -> Result<ShortenResponse>;
 
@@ -40,26 +139,116 @@ pub trait ShortenService {
 
     /// Get available domains for short URLs
     fn get_available_domains(&self) -> Result<GetAvailableDomainsResponse>;
+
+    /// Check whether a custom slug is still free on the given domain
+    ///
+    /// This is a best-effort check that only sees links owned by this
+    /// account: a slug taken by another account is reported as available,
+    /// and creating it still fails with a conflict.
+    fn is_slug_available(&self, domain: &DomainName, slug: &Slug) -> Result<bool>;
 }
 
 impl ShortenService for Client {
+    /// The configured default domain, else the service's default
+    fn default_domain(&self) -> DomainName {
+        self.config().default_domain.clone().unwrap_or_default()
+    }
+
     /// Shorten a URL using the configured service
-    fn shorten(&self, request: ShortenRequest) -> Result<ShortenResponse> {
+    fn shorten(&self, mut request: ShortenRequest) -> Result<ShortenResponse> {
         if !self.is_valid_url(&request.target_url) {
             return Err(Error::InvalidUrl(request.target_url));
         }
 
-        self.execute_request(reqwest::Method::POST, "/shorten", request)
+        let domain = request
+            .domain
+            .get_or_insert_with(|| self.default_domain())
+            .clone();
+
+        if self.is_dry_run() {
+            self.log_dry_run(reqwest::Method::POST, "/shorten", &request)?;
+            return Ok(ShortenResponse::dry_run(&domain, request.custom_slug));
+        }
+
+        let response: ShortenResponse =
+            self.execute_request(reqwest::Method::POST, "/shorten", &request)?;
+
+        self.record(|| LedgerEntry {
+            short_url: Some(response.data.short_url.clone()),
+            target_url: Some(request.target_url),
+            title: request.title,
+            tag_ids: request.tag_ids.unwrap_or_default(),
+            expire_at: request.expire_at,
+            ..LedgerEntry::new(
+                LedgerAction::Create,
+                CreatedResource::Link {
+                    domain,
+                    slug: response.data.slug.clone(),
+                },
+            )
+        });
+
+        Ok(response)
+    }
+
+    /// Shorten a URL and bind the result to this client
+    fn shorten_handle(&self, request: ShortenRequest) -> Result<LinkHandle> {
+        let domain = request
+            .domain
+            .clone()
+            .unwrap_or_else(|| self.default_domain());
+        let response = self.shorten(request)?;
+
+        Ok(LinkHandle::from_data(self.clone(), domain, response.data))
     }
 
     /// Update a short URL
     fn update_short_url(&self, request: UpdateShortURLRequest) -> Result<ShortenResponse> {
-        self.execute_request(reqwest::Method::PUT, "/shorten", request)
+        if self.is_dry_run() {
+            self.log_dry_run(reqwest::Method::PUT, "/shorten", &request)?;
+            return Ok(ShortenResponse::dry_run(
+                &request.domain,
+                Some(request.slug),
+            ));
+        }
+
+        let response = self.execute_request(reqwest::Method::PUT, "/shorten", &request)?;
+
+        self.record(|| LedgerEntry {
+            target_url: Some(request.target_url),
+            title: request.title,
+            ..LedgerEntry::new(
+                LedgerAction::Update,
+                CreatedResource::Link {
+                    domain: request.domain,
+                    slug: request.slug,
+                },
+            )
+        });
+
+        Ok(response)
     }
 
     /// Delete a shortened URL using the configured service
     fn delete(&self, request: DeleteRequest) -> Result<DeleteResponse> {
-        self.execute_request(reqwest::Method::DELETE, "/shorten", request)
+        if self.is_dry_run() {
+            self.log_dry_run(reqwest::Method::DELETE, "/shorten", &request)?;
+            return Ok(DeleteResponse::dry_run());
+        }
+
+        let response = self.execute_request(reqwest::Method::DELETE, "/shorten", &request)?;
+
+        self.record(|| {
+            LedgerEntry::new(
+                LedgerAction::Delete,
+                CreatedResource::Link {
+                    domain: request.domain,
+                    slug: request.slug,
+                },
+            )
+        });
+
+        Ok(response)
     }
 
     /// Get usage statistics for a short URL
@@ -68,8 +257,8 @@ impl ShortenService for Client {
         request: GetLinkVisitStatRequest,
     ) -> Result<GetLinkVisitStatResponse> {
         let mut params = vec![
-            ("domain", request.domain),
-            ("slug", request.slug),
+            ("domain", request.domain.into_inner()),
+            ("slug", request.slug.into_inner()),
         ];
 
         if let Some(period) = request.period {
@@ -83,28 +272,42 @@ impl ShortenService for Client {
     fn get_available_domains(&self) -> Result<GetAvailableDomainsResponse> {
         self.execute_request_no_body(reqwest::Method::GET, "/domains")
     }
+
+    /// Check slug availability by looking up the link's visit statistics,
+    /// which only exist for links this account has created
+    fn is_slug_available(&self, domain: &DomainName, slug: &Slug) -> Result<bool> {
+        match self.get_link_visit_stat(GetLinkVisitStatRequest {
+            domain: domain.clone(),
+            slug: slug.clone(),
+            period: None,
+        }) {
+            Ok(_) => Ok(false),
+            Err(Error::NotFound) | Err(Error::ServerError { status: 404, .. }) => Ok(true),
+            Err(e) => Err(e),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::DEFAULT_DOMAIN;
+    use crate::config::{Config, DEFAULT_DOMAIN};
     use crate::test_helpers::helpers::{
-        assert_not_empty, assert_status_ok, create_test_client, get_api_key_or_skip,
+        assert_not_empty, assert_status_ok, create_test_client, get_api_key_or_skip, serve,
     };
     use crate::url::builder::UrlShortenerRequestBuilder;
 
     /// Helper function to create a shortened URL for testing
-    fn create_test_short_url() -> Result<(Client, String)> {
+    fn create_test_short_url() -> Result<(Client, Slug)> {
         let client = create_test_client()?;
 
         let request = UrlShortenerRequestBuilder::new("https://git.guanwaii.com/login")?
-            .with_domain(DEFAULT_DOMAIN)
+            .with_domain(DEFAULT_DOMAIN)?
             .build();
 
         let response = client.shorten(request)?;
         assert_status_ok(response.code);
-        assert_not_empty(&response.data.slug);
+        assert_not_empty(response.data.slug.as_str());
         assert_not_empty(&response.data.short_url);
         assert!(client.is_valid_url(&response.data.short_url));
 
@@ -121,18 +324,18 @@ mod tests {
         let client = create_test_client()?;
 
         let request = UrlShortenerRequestBuilder::new("https://git.guanwaii.com/login")?
-            .with_domain(DEFAULT_DOMAIN)
+            .with_domain(DEFAULT_DOMAIN)?
             .build();
 
         let response = client.shorten(request)?;
         assert_status_ok(response.code);
-        assert_not_empty(&response.data.slug);
+        assert_not_empty(response.data.slug.as_str());
         assert_not_empty(&response.data.short_url);
         assert!(client.is_valid_url(&response.data.short_url));
 
         // Cleanup: delete the created short URL
         let _ = client.delete(DeleteRequest {
-            domain: DEFAULT_DOMAIN.to_string(),
+            domain: DomainName::default(),
             slug: response.data.slug,
         });
 
@@ -151,7 +354,7 @@ mod tests {
 
         // Test delete URL
         let delete_request = DeleteRequest {
-            domain: DEFAULT_DOMAIN.to_string(),
+            domain: DomainName::default(),
             slug,
         };
 
@@ -160,4 +363,221 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_dry_run_does_not_send() -> Result<()> {
+        // The base URL is unreachable, so any real request would fail
+        let config = Config::new("http://127.0.0.1:9")
+            .with_max_retries(0)
+            .with_dry_run(true);
+        let client = Client::new(config)?;
+
+        let request = UrlShortenerRequestBuilder::new("https://example.com/")?
+            .with_custom_alias("launch")?
+            .build();
+        let response = client.shorten(request)?;
+        assert_eq!(response.data.slug, "launch");
+        assert_eq!(response.data.short_url, "https://s.ee/launch");
+
+        let response = client.delete(DeleteRequest {
+            domain: DomainName::default(),
+            slug: response.data.slug,
+        })?;
+        assert_status_ok(response.code);
+
+        // Invalid requests are still rejected
+        let request = ShortenRequest {
+            target_url: "not-a-url".to_string(),
+            ..Default::default()
+        };
+        assert!(matches!(client.shorten(request), Err(Error::InvalidUrl(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_shorten_uses_default_domain() -> Result<()> {
+        let config = Config::new("http://127.0.0.1:9")
+            .with_dry_run(true)
+            .with_default_domain(DomainName::new("example.link")?);
+        let client = Client::new(config)?;
+
+        let request = UrlShortenerRequestBuilder::new("https://example.com/")?
+            .with_custom_alias("launch")?
+            .build();
+        let response = client.shorten(request)?;
+        assert_eq!(response.data.short_url, "https://example.link/launch");
+
+        let request = UrlShortenerRequestBuilder::new("https://example.com/")?
+            .with_custom_alias("launch")?
+            .with_domain(DEFAULT_DOMAIN)?
+            .build();
+        let response = client.shorten(request)?;
+        assert_eq!(response.data.short_url, "https://s.ee/launch");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_slug_available_when_not_found() -> Result<()> {
+        let found = r#"{"code":200,"message":"success","data":{"visit_count":3}}"#;
+        let (base_url, server) = serve(vec![
+            "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string(),
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                found.len(),
+                found
+            ),
+        ]);
+        let client = Client::new(Config::new(base_url).with_api_key("key"))?;
+        let domain = DomainName::default();
+        let slug = Slug::new("launch")?;
+
+        assert!(client.is_slug_available(&domain, &slug)?);
+        assert!(!client.is_slug_available(&domain, &slug)?);
+
+        server.join().unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_slug_available() -> Result<()> {
+        // Skip test if API key is not set
+        if get_api_key_or_skip().is_none() {
+            return Ok(());
+        }
+
+        let (client, slug) = create_test_short_url()?;
+        let domain = DomainName::default();
+        assert!(!client.is_slug_available(&domain, &slug)?);
+
+        // Cleanup: delete the created short URL
+        let _ = client.delete(DeleteRequest { domain, slug });
+
+        Ok(())
+    }
+
+    /// A `200 OK` JSON response
+    fn ok(body: &str) -> String {
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )
+    }
+
+    fn shortened(slug: &str) -> String {
+        ok(&format!(
+            r#"{{"code":200,"message":"success","data":{{"custom_slug":null,"short_url":"https://s.ee/{0}","slug":"{0}"}}}}"#,
+            slug
+        ))
+    }
+
+    #[test]
+    fn test_shorten_idempotent_offline() -> Result<()> {
+        let (base_url, server) = serve(vec![
+            // Miss: the link is created
+            shortened("first"),
+            // Hit: the indexed link still exists
+            ok(r#"{"code":200,"message":"success","data":{"visit_count":1}}"#),
+            // Hit on a link deleted elsewhere: it is created again
+            "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string(),
+            shortened("second"),
+        ]);
+        let client = Client::new(Config::new(base_url).with_api_key("key"))?;
+        let index = LinkIndex::new();
+        let request = UrlShortenerRequestBuilder::new("https://example.com/")?.build();
+
+        let miss = client.shorten_idempotent(request.clone(), &index, MatchKey::Target)?;
+        assert_eq!(miss.data.slug, "first");
+        assert_eq!(index.len(), 1);
+
+        let hit = client.shorten_idempotent(request.clone(), &index, MatchKey::Target)?;
+        assert_eq!(hit.data.slug, "first");
+
+        let recreated = client.shorten_idempotent(request, &index, MatchKey::Target)?;
+        assert_eq!(recreated.data.slug, "second");
+        assert_eq!(index.len(), 1);
+
+        server.join().unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn test_shorten_idempotent_dry_run_is_not_indexed() -> Result<()> {
+        let config = Config::new("http://127.0.0.1:9")
+            .with_max_retries(0)
+            .with_dry_run(true);
+        let client = Client::new(config)?;
+        let index = LinkIndex::new();
+
+        let request = UrlShortenerRequestBuilder::new("https://example.com/")?
+            .with_custom_alias("launch")?
+            .build();
+        let response = client.shorten_idempotent(request, &index, MatchKey::Target)?;
+        assert_eq!(response.data.slug, "launch");
+        assert!(index.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_shorten_idempotent() -> Result<()> {
+        // Skip test if API key is not set
+        if get_api_key_or_skip().is_none() {
+            return Ok(());
+        }
+
+        let client = create_test_client()?;
+        let index = LinkIndex::new();
+
+        let request = UrlShortenerRequestBuilder::new("https://git.guanwaii.com/login")?
+            .with_domain(DEFAULT_DOMAIN)?
+            .build();
+
+        let first = client.shorten_idempotent(request.clone(), &index, MatchKey::Target)?;
+        let second = client.shorten_idempotent(request, &index, MatchKey::Target)?;
+        assert_eq!(first.data.slug, second.data.slug);
+        assert_eq!(index.len(), 1);
+
+        // Cleanup: delete the created short URL
+        let _ = client.delete(DeleteRequest {
+            domain: DomainName::default(),
+            slug: first.data.slug,
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_link_handle_lifecycle() -> Result<()> {
+        // Skip test if API key is not set
+        if get_api_key_or_skip().is_none() {
+            return Ok(());
+        }
+
+        let client = create_test_client()?;
+
+        let request = UrlShortenerRequestBuilder::new("https://git.guanwaii.com/login")?
+            .with_domain(DEFAULT_DOMAIN)?
+            .build();
+
+        let link = client.shorten_handle(request)?;
+        assert_eq!(link.domain(), DEFAULT_DOMAIN);
+        assert_not_empty(link.slug().as_str());
+
+        let update_response = link
+            .update("https://git.guanwaii.com/explore")
+            .with_title("Rust SDK Handle Test")
+            .send()?;
+        assert_status_ok(update_response.code);
+
+        let stats_response = link.stats()?;
+        assert_status_ok(stats_response.code);
+
+        let delete_response = link.delete()?;
+        assert_status_ok(delete_response.code);
+
+        Ok(())
+    }
 }
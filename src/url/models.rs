@@ -8,23 +8,25 @@
  * Author: mingcheng <mingcheng@apache.org>
  * File Created: 2025-10-23 11:23:53
  *
- * Modified By: mingcheng <mingcheng@apache.org>
- * Last Modified: 2025-10-23 21:52:31
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 08:41:12
  */
 
 use std::str;
 
-use crate::config::DEFAULT_DOMAIN;
+use crate::types::{DRY_RUN_MESSAGE, DRY_RUN_SLUG, DomainName, Secret, Slug, TagId};
 use serde::{Deserialize, Serialize};
 
 /// Request structure for URL shortening
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
 pub struct ShortenRequest {
     /// The URL to be shortened
     pub target_url: String,
 
-    /// The domain to use for the short URL
-    pub domain: String,
+    /// The domain to use for the short URL, the client's default domain
+    /// when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<DomainName>,
 
     /// Optional title for the short URL
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -32,7 +34,7 @@ pub struct ShortenRequest {
 
     /// Custom slug for the short URL
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub custom_slug: Option<String>,
+    pub custom_slug: Option<Slug>,
 
     /// Optional redirect URL after expiration
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -44,36 +46,21 @@ pub struct ShortenRequest {
 
     /// Optional password for accessing the short URL
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub password: Option<String>,
+    pub password: Option<Secret>,
 
     /// Optional list of tag IDs to associate with the short URL
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tag_ids: Option<Vec<u32>>,
-}
-
-impl Default for ShortenRequest {
-    fn default() -> Self {
-        Self {
-            target_url: String::new(),
-            domain: DEFAULT_DOMAIN.to_string(),
-            title: None,
-            custom_slug: None,
-            expiration_redirect_url: None,
-            expire_at: None,
-            password: None,
-            tag_ids: None,
-        }
-    }
+    pub tag_ids: Option<Vec<TagId>>,
 }
 
 /// Request structure for updating a short URL
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct UpdateShortURLRequest {
     /// The domain of the short URL
-    pub domain: String,
+    pub domain: DomainName,
 
     /// The slug of the short URL
-    pub slug: String,
+    pub slug: Slug,
 
     /// The new target URL
     pub target_url: String,
@@ -91,27 +78,52 @@ pub struct ShortenResponse {
     pub data: ShortenData,
 }
 
+impl ShortenResponse {
+    /// Synthetic response for a link that was not actually sent
+    pub(crate) fn dry_run(domain: &DomainName, custom_slug: Option<Slug>) -> Self {
+        let slug = custom_slug
+            .clone()
+            .unwrap_or_else(|| Slug::new(DRY_RUN_SLUG).expect("valid dry-run slug"));
+
+        Self {
+            code: reqwest::StatusCode::OK.as_u16(),
+            message: DRY_RUN_MESSAGE.to_string(),
+            data: ShortenData {
+                custom_slug,
+                short_url: format!("https://{}/{}", domain, slug),
+                slug,
+            },
+        }
+    }
+
+    /// Whether this is a synthetic dry-run response rather than a link the
+    /// service created
+    pub(crate) fn is_dry_run(&self) -> bool {
+        self.message == DRY_RUN_MESSAGE
+    }
+}
+
 /// Data structure containing shortened URL information
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ShortenData {
     /// The custom slug if provided
-    pub custom_slug: Option<String>,
+    pub custom_slug: Option<Slug>,
 
     /// The full shortened URL
     pub short_url: String,
 
     /// The effective slug
-    pub slug: String,
+    pub slug: Slug,
 }
 
 /// Request structure for deleting a short URL
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct DeleteRequest {
     /// The domain of the short URL
-    pub domain: String,
+    pub domain: DomainName,
 
     /// The slug of the short URL to delete
-    pub slug: String,
+    pub slug: Slug,
 }
 
 // Response type for URL deletion
@@ -122,11 +134,22 @@ pub struct DeleteResponse {
     pub data: Option<serde_json::Value>,
 }
 
+impl DeleteResponse {
+    /// Synthetic response for a deletion that was not actually sent
+    pub(crate) fn dry_run() -> Self {
+        Self {
+            code: reqwest::StatusCode::OK.as_u16(),
+            message: DRY_RUN_MESSAGE.to_string(),
+            data: None,
+        }
+    }
+}
+
 /// Request structure for link visit statistics
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct GetLinkVisitStatRequest {
-    pub domain: String,
-    pub slug: String,
+    pub domain: DomainName,
+    pub slug: Slug,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub period: Option<String>,
 }
@@ -148,7 +171,7 @@ pub struct GetLinkVisitStatResponse {
 /// Data structure for available domains
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct DomainsData {
-    pub domains: Vec<String>,
+    pub domains: Vec<DomainName>,
 }
 
 /// Response structure for available domains
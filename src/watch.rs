@@ -0,0 +1,411 @@
+/*!
+ * Copyright (c) 2026 S.EE Development Team
+ *
+ * This source code is licensed under the MIT License,
+ * which is located in the LICENSE file in the source tree's root directory.
+ *
+ * File: watch.rs
+ * Author: S.EE Development Team <dev@s.ee>
+ * File Created: 2026-10-20 04:48:15
+ *
+ * Modified By: S.EE Development Team <dev@s.ee>
+ * Last Modified: 2026-10-20 04:48:15
+ */
+
+//! Watch a directory and upload files as they appear.
+//!
+//! A file is uploaded once it stopped changing: no events for
+//! [`WatchOptions::settle`] and the same size and modification time as when
+//! last seen, so files still being written or copied are not sent half done.
+//! Hidden files and the temporary files of browsers and editors are skipped.
+//!
+//! Every upload is recorded in the [ledger](crate::ledger) when one is
+//! configured, including its delete URL.
+
+use crate::client::Client;
+use crate::error::{Error, Result};
+use crate::file::FileService;
+use crate::types::FileKey;
+use notify::event::{EventKind, ModifyKind};
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::ops::ControlFlow;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::{Duration, Instant, SystemTime};
+
+/// Default time a file must stay unchanged before it is uploaded
+pub const DEFAULT_SETTLE: Duration = Duration::from_secs(2);
+
+/// Suffixes of files that are still being downloaded or edited
+const TEMPORARY_SUFFIXES: &[&str] = &[
+    ".part",
+    ".partial",
+    ".crdownload",
+    ".download",
+    ".tmp",
+    ".swp",
+    "~",
+];
+
+/// Options for [`watch`]
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+    /// How long a file must stay unchanged before it is uploaded
+    pub settle: Duration,
+
+    /// Also watch subdirectories
+    pub recursive: bool,
+
+    /// Command told about every upload
+    pub hook: Option<NotifyHook>,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            settle: DEFAULT_SETTLE,
+            recursive: false,
+            hook: None,
+        }
+    }
+}
+
+impl WatchOptions {
+    /// Set how long a file must stay unchanged before it is uploaded
+    pub fn with_settle(mut self, settle: Duration) -> Self {
+        self.settle = settle;
+        self
+    }
+
+    /// Set whether subdirectories are watched too
+    pub fn with_recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Run a command after every upload
+    pub fn with_hook(mut self, hook: NotifyHook) -> Self {
+        self.hook = Some(hook);
+        self
+    }
+}
+
+/// A command run after every upload, such as `wl-copy` or `pbcopy`
+///
+/// The file URL is written to its stdin, and `SEE_URL`, `SEE_DELETE_URL`,
+/// `SEE_FILE_KEY` and `SEE_FILE` are set in its environment.
+#[derive(Debug, Clone)]
+pub struct NotifyHook {
+    program: String,
+    args: Vec<String>,
+}
+
+impl NotifyHook {
+    /// Run `program` without arguments
+    pub fn new(program: impl Into<String>) -> Self {
+        Self {
+            program: program.into(),
+            args: Vec::new(),
+        }
+    }
+
+    /// Add an argument
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Add arguments
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Run the command for an upload and wait for it
+    pub fn run(&self, upload: &Upload) -> Result<()> {
+        let mut child = Command::new(&self.program)
+            .args(&self.args)
+            .env("SEE_URL", &upload.url)
+            .env("SEE_DELETE_URL", &upload.delete_url)
+            .env("SEE_FILE_KEY", upload.key.as_ref())
+            .env("SEE_FILE", &upload.path)
+            .stdin(Stdio::piped())
+            .spawn()?;
+
+        // Hooks that ignore stdin may exit before the URL is written
+        if let Some(mut stdin) = child.stdin.take()
+            && let Err(e) = stdin.write_all(upload.url.as_bytes())
+            && e.kind() != std::io::ErrorKind::BrokenPipe
+        {
+            return Err(e.into());
+        }
+
+        let status = child.wait()?;
+        if !status.success() {
+            return Err(Error::GenericError(format!(
+                "{} failed with {}",
+                self.program, status
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// A file that was uploaded
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Upload {
+    pub path: PathBuf,
+
+    /// Public URL of the file
+    pub url: String,
+
+    /// Key of the file, for [`FileService::delete_file`]
+    pub key: FileKey,
+
+    /// URL that deletes the file
+    pub delete_url: String,
+}
+
+/// What happened to a file
+#[derive(Debug)]
+pub enum WatchEvent {
+    Uploaded(Upload),
+    Failed { path: PathBuf, error: Error },
+}
+
+/// Size and modification time, to tell whether a file changed
+type Snapshot = (u64, Option<SystemTime>);
+
+fn snapshot(path: &Path) -> Option<Snapshot> {
+    let metadata = fs::metadata(path).ok().filter(|m| m.is_file())?;
+    Some((metadata.len(), metadata.modified().ok()))
+}
+
+/// Whether a file is hidden or temporary and never uploaded
+pub fn is_ignored(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return true;
+    };
+
+    name.starts_with('.')
+        || TEMPORARY_SUFFIXES
+            .iter()
+            .any(|suffix| name.to_ascii_lowercase().ends_with(suffix))
+}
+
+/// Watch `dir` and upload new and changed files until `on_event` breaks
+///
+/// Files already in the directory are left alone. `on_event` is called after
+/// every upload attempt; a failed upload or hook does not stop the watch.
+pub fn watch<F>(
+    client: &Client,
+    dir: impl AsRef<Path>,
+    options: &WatchOptions,
+    mut on_event: F,
+) -> Result<()>
+where
+    F: FnMut(WatchEvent) -> ControlFlow<()>,
+{
+    let dir = dir.as_ref();
+    let (tx, rx) = mpsc::channel();
+    let mut watcher =
+        RecommendedWatcher::new(tx, notify::Config::default()).map_err(|e| watch_error(dir, e))?;
+    let mode = if options.recursive {
+        RecursiveMode::Recursive
+    } else {
+        RecursiveMode::NonRecursive
+    };
+    watcher.watch(dir, mode).map_err(|e| watch_error(dir, e))?;
+
+    // Changed files waiting to settle, with when they were last seen changing
+    let mut pending: HashMap<PathBuf, (Instant, Option<Snapshot>)> = HashMap::new();
+    let mut uploaded: HashMap<PathBuf, Snapshot> = HashMap::new();
+    let tick = (options.settle / 4).max(Duration::from_millis(10));
+
+    loop {
+        match rx.recv_timeout(tick) {
+            Ok(Ok(event)) if is_change(&event.kind) => {
+                for path in event.paths {
+                    if !is_ignored(&path) {
+                        let seen = snapshot(&path);
+                        pending.insert(path, (Instant::now(), seen));
+                    }
+                }
+            }
+            Ok(Ok(_)) | Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Ok(Err(e)) => log::warn!("watch error on {}: {}", dir.display(), e),
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                return Err(Error::GenericError(format!(
+                    "stopped watching {}",
+                    dir.display()
+                )));
+            }
+        }
+
+        let settled: Vec<PathBuf> = pending
+            .iter()
+            .filter(|(_, (changed, _))| changed.elapsed() >= options.settle)
+            .map(|(path, _)| path.clone())
+            .collect();
+
+        for path in settled {
+            let Some((_, seen)) = pending.remove(&path) else {
+                continue;
+            };
+            let Some(current) = snapshot(&path) else {
+                // Removed or renamed away before it settled
+                continue;
+            };
+
+            if seen != Some(current) {
+                pending.insert(path, (Instant::now(), Some(current)));
+                continue;
+            }
+            if uploaded.get(&path) == Some(&current) {
+                continue;
+            }
+
+            let event = match upload(client, &path, options) {
+                Ok(done) => {
+                    uploaded.insert(path, current);
+                    WatchEvent::Uploaded(done)
+                }
+                Err(error) => WatchEvent::Failed { path, error },
+            };
+
+            if on_event(event).is_break() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Whether an event may have changed the contents of a file
+fn is_change(kind: &EventKind) -> bool {
+    matches!(
+        kind,
+        EventKind::Create(_)
+            | EventKind::Modify(ModifyKind::Data(_) | ModifyKind::Name(_) | ModifyKind::Any)
+    )
+}
+
+fn upload(client: &Client, path: &Path, options: &WatchOptions) -> Result<Upload> {
+    let response = client.upload_file(path)?;
+    let upload = Upload {
+        path: path.to_path_buf(),
+        url: response.data.url,
+        key: response.data.hash,
+        delete_url: response.data.delete,
+    };
+
+    if let Some(hook) = &options.hook
+        && let Err(e) = hook.run(&upload)
+    {
+        log::warn!("notify hook failed for {}: {}", upload.path.display(), e);
+    }
+
+    Ok(upload)
+}
+
+fn watch_error(dir: &Path, error: notify::Error) -> Error {
+    match error.kind {
+        notify::ErrorKind::Io(e) => Error::IoError(e),
+        _ => Error::GenericError(format!("cannot watch {}: {}", dir.display(), error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use std::env;
+    use std::thread;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let mut dir = env::temp_dir();
+        dir.push(format!("see-sdk-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_ignored_files() {
+        assert!(is_ignored(Path::new("shots/.DS_Store")));
+        assert!(is_ignored(Path::new("build.zip.part")));
+        assert!(is_ignored(Path::new("video.CRDOWNLOAD")));
+        assert!(is_ignored(Path::new("notes.txt~")));
+        assert!(!is_ignored(Path::new("shots/screenshot.png")));
+    }
+
+    #[test]
+    fn test_watch_uploads_settled_file() {
+        let dir = temp_dir("watch");
+        let client = Client::new(Config::default().with_dry_run(true)).unwrap();
+        let options = WatchOptions::default().with_settle(Duration::from_millis(300));
+
+        let writer_dir = dir.clone();
+        let writer = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(200));
+            fs::write(writer_dir.join(".hidden"), b"skip").unwrap();
+            let mut file = fs::File::create(writer_dir.join("shot.png")).unwrap();
+            for _ in 0..3 {
+                file.write_all(b"partial").unwrap();
+                file.flush().unwrap();
+                thread::sleep(Duration::from_millis(50));
+            }
+        });
+
+        let mut events = Vec::new();
+        watch(&client, &dir, &options, |event| {
+            events.push(event);
+            ControlFlow::Break(())
+        })
+        .unwrap();
+        writer.join().unwrap();
+
+        let [WatchEvent::Uploaded(upload)] = events.as_slice() else {
+            panic!("expected one upload, got {:?}", events);
+        };
+        assert_eq!(upload.path, dir.join("shot.png"));
+        assert!(upload.url.ends_with("shot.png"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_hook_receives_url() {
+        let dir = temp_dir("watch-hook");
+        let out = dir.join("hook.out");
+        let hook = NotifyHook::new("sh").arg("-c").arg(format!(
+            "cat > {} && echo \" $SEE_FILE_KEY\" >> {0}",
+            out.display()
+        ));
+
+        hook.run(&Upload {
+            path: dir.join("shot.png"),
+            url: "https://example.com/shot.png".to_string(),
+            key: FileKey::new("abc123").unwrap(),
+            delete_url: "https://example.com/delete/abc123".to_string(),
+        })
+        .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&out).unwrap().trim(),
+            "https://example.com/shot.png abc123"
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}